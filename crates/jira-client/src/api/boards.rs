@@ -22,18 +22,6 @@ impl ApiClient {
         ).await
     }
 
-    pub async fn get_filter(&self, filter_id: u64, auth: &Auth) -> Result<Value> {
-        tracing::info!(target: "jira", op = "get_filter", filter_id = filter_id);
-
-        self.make_request(
-            reqwest::Method::GET,
-            &format!("/rest/api/3/filter/{}", filter_id),
-            auth,
-            None,
-            None,
-        ).await
-    }
-
     pub async fn get_board_issues(
         &self,
         board_id: u64,
@@ -42,20 +30,36 @@ impl ApiClient {
     ) -> Result<Vec<Issue>> {
         tracing::info!(target: "jira", op = "get_board_issues", board_id = board_id, limit = limit);
 
+        self.page_agile_issues(&format!("/rest/agile/1.0/board/{}/issue", board_id), None, "*all", limit, auth)
+            .await
+    }
+
+    /// Collect up to `limit` issues from an Agile issue listing (board, sprint, ...), page by page
+    async fn page_agile_issues(
+        &self,
+        path: &str,
+        jql: Option<&str>,
+        fields: &str,
+        limit: usize,
+        auth: &Auth,
+    ) -> Result<Vec<Issue>> {
         let mut start_at = 0usize;
         let mut out: Vec<Issue> = Vec::new();
         let page_size = limit.min(100);
 
         while out.len() < limit {
-            let query_params = vec![
-                ("fields".into(), "*all".into()),
+            let mut query_params = vec![
+                ("fields".into(), fields.to_string()),
                 ("startAt".into(), start_at.to_string()),
                 ("maxResults".into(), page_size.to_string()),
             ];
+            if let Some(q) = jql {
+                query_params.push(("jql".into(), q.to_string()));
+            }
 
             let v = self.make_request(
                 reqwest::Method::GET,
-                &format!("/rest/agile/1.0/board/{}/issue", board_id),
+                path,
                 auth,
                 Some(query_params),
                 None,
@@ -71,6 +75,7 @@ impl ApiClient {
                 break;
             }
 
+            let page_len = issues.len();
             for it in issues {
                 let key = it
                     .get("key")
@@ -87,7 +92,14 @@ impl ApiClient {
                 }
             }
 
-            start_at += page_size;
+            // Jira may cap maxResults below what was asked, so only its own paging fields end the listing
+            let is_last = v.get("isLast").and_then(|l| l.as_bool()).unwrap_or(false);
+            let total = v.get("total").and_then(|t| t.as_u64()).map(|t| t as usize);
+            if is_last || total.is_some_and(|t| start_at + page_len >= t) {
+                break;
+            }
+
+            start_at += page_len;
         }
 
         Ok(out)
@@ -179,14 +191,13 @@ impl ApiClient {
                     }
                 }
 
-                let total = v.get("total").and_then(|t| t.as_u64()).unwrap_or(0) as usize;
-                let fetched = all_sprints.len();
-
-                if fetched >= total || values.is_empty() {
+                // The sprint endpoint reports `isLast` but no `total`
+                let is_last = v.get("isLast").and_then(|l| l.as_bool()).unwrap_or(true);
+                if is_last || values.is_empty() {
                     break;
                 }
 
-                start_at += MAX_RESULTS;
+                start_at += values.len();
             } else {
                 break;
             }
@@ -267,4 +278,187 @@ impl ApiClient {
         let sprint: Sprint = serde_json::from_value(response)?;
         Ok(sprint)
     }
+
+    pub async fn get_sprint_issues(
+        &self,
+        sprint_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        limit: usize,
+        auth: &Auth,
+    ) -> Result<Vec<Issue>> {
+        tracing::info!(target: "jira", op = "get_sprint_issues", sprint_id = sprint_id, jql = ?jql, limit = limit);
+
+        self.page_agile_issues(&format!("/rest/agile/1.0/sprint/{}/issue", sprint_id), jql, fields, limit, auth)
+            .await
+    }
+
+    pub async fn create_sprint(
+        &self,
+        payload: &Value,
+        auth: &Auth,
+    ) -> Result<Sprint> {
+        tracing::info!(target: "jira", op = "create_sprint", payload = ?payload);
+
+        let response: Value = self.make_request(
+            reqwest::Method::POST,
+            "/rest/agile/1.0/sprint",
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await?;
+
+        let sprint: Sprint = serde_json::from_value(response)?;
+        Ok(sprint)
+    }
+
+    /// Partial update: only the keys present in `payload` change (also used to start and close sprints)
+    pub async fn update_sprint(
+        &self,
+        sprint_id: u64,
+        payload: &Value,
+        auth: &Auth,
+    ) -> Result<Sprint> {
+        tracing::info!(target: "jira", op = "update_sprint", sprint_id = sprint_id, payload = ?payload);
+
+        let response: Value = self.make_request(
+            reqwest::Method::POST,
+            &format!("/rest/agile/1.0/sprint/{}", sprint_id),
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await?;
+
+        let sprint: Sprint = serde_json::from_value(response)?;
+        Ok(sprint)
+    }
+
+    pub async fn delete_sprint(
+        &self,
+        sprint_id: u64,
+        auth: &Auth,
+    ) -> Result<()> {
+        tracing::info!(target: "jira", op = "delete_sprint", sprint_id = sprint_id);
+
+        self.make_request(
+            reqwest::Method::DELETE,
+            &format!("/rest/agile/1.0/sprint/{}", sprint_id),
+            auth,
+            None,
+            None,
+        ).await?;
+
+        Ok(())
+    }
+
+    /// Velocity chart data from the Greenhopper (Jira Software UI) API: `sprints` and `velocityStatEntries`
+    pub async fn get_velocity_chart(
+        &self,
+        board_id: u64,
+        auth: &Auth,
+    ) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_velocity_chart", board_id = board_id);
+
+        self.make_request(
+            reqwest::Method::GET,
+            "/rest/greenhopper/1.0/rapid/charts/velocity",
+            auth,
+            Some(vec![("rapidViewId".into(), board_id.to_string())]),
+            None,
+        ).await
+    }
+
+    pub async fn get_board(
+        &self,
+        board_id: u64,
+        auth: &Auth,
+    ) -> Result<Board> {
+        tracing::info!(target: "jira", op = "get_board", board_id = board_id);
+
+        let response: Value = self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/agile/1.0/board/{}", board_id),
+            auth,
+            None,
+            None,
+        ).await?;
+
+        let board: Board = serde_json::from_value(response)?;
+        Ok(board)
+    }
+
+    pub async fn get_backlog(
+        &self,
+        board_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        start_at: usize,
+        max_results: usize,
+        auth: &Auth,
+    ) -> Result<Value> {
+        tracing::info!(
+            target: "jira",
+            op = "get_backlog",
+            board_id = board_id,
+            jql = ?jql,
+            start_at = start_at,
+            max_results = max_results
+        );
+
+        let mut query_params = vec![
+            ("fields".into(), fields.to_string()),
+            ("startAt".into(), start_at.to_string()),
+            ("maxResults".into(), max_results.to_string()),
+        ];
+        if let Some(q) = jql {
+            query_params.push(("jql".into(), q.to_string()));
+        }
+
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/agile/1.0/board/{}/backlog", board_id),
+            auth,
+            Some(query_params),
+            None,
+        ).await
+    }
+
+    /// Rank issues before or after another issue; a 207 response carries per-issue errors in `entries`
+    pub async fn rank_issues(
+        &self,
+        issue_keys: &[String],
+        rank_before: Option<&str>,
+        rank_after: Option<&str>,
+        rank_field_id: Option<u64>,
+        auth: &Auth,
+    ) -> Result<Value> {
+        tracing::info!(
+            target: "jira",
+            op = "rank_issues",
+            issues = ?issue_keys,
+            before = ?rank_before,
+            after = ?rank_after
+        );
+
+        let mut body = serde_json::json!({
+            "issues": issue_keys
+        });
+        if let Some(key) = rank_before {
+            body["rankBeforeIssue"] = Value::String(key.to_string());
+        }
+        if let Some(key) = rank_after {
+            body["rankAfterIssue"] = Value::String(key.to_string());
+        }
+        if let Some(id) = rank_field_id {
+            body["rankCustomFieldId"] = Value::from(id);
+        }
+
+        self.make_request(
+            reqwest::Method::PUT,
+            "/rest/agile/1.0/issue/rank",
+            auth,
+            None,
+            Some(body),
+        ).await
+    }
 }
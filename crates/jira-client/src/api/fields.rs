@@ -0,0 +1,31 @@
+use anyhow::Result;
+
+use crate::auth::Auth;
+use crate::models::Field;
+use super::ApiClient;
+
+impl ApiClient {
+    pub async fn list_fields(&self, auth: &Auth) -> Result<Vec<Field>> {
+        tracing::info!(target: "jira", op = "list_fields");
+
+        let v = self.make_request(
+            reqwest::Method::GET,
+            "/rest/api/3/field",
+            auth,
+            None,
+            None,
+        ).await?;
+
+        let mut out = Vec::new();
+
+        if let Some(fields) = v.as_array() {
+            for field_val in fields {
+                if let Ok(field) = serde_json::from_value::<Field>(field_val.clone()) {
+                    out.push(field);
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
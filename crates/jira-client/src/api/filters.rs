@@ -0,0 +1,124 @@
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::auth::Auth;
+use super::ApiClient;
+
+const FILTER_EXPAND: &str = "description,favourite,jql,owner,sharePermissions,viewUrl";
+
+impl ApiClient {
+    pub async fn get_filter(&self, filter_id: u64, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_filter", filter_id = filter_id);
+
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/filter/{}", filter_id),
+            auth,
+            None,
+            None,
+        ).await
+    }
+
+    pub async fn list_favourite_filters(&self, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "list_favourite_filters");
+
+        let query_params = vec![("expand".into(), FILTER_EXPAND.to_string())];
+
+        let v = self.make_request(
+            reqwest::Method::GET,
+            "/rest/api/3/filter/favourite",
+            auth,
+            Some(query_params),
+            None,
+        ).await?;
+
+        Ok(v.as_array().cloned().unwrap_or_default())
+    }
+
+    pub async fn search_filters(
+        &self,
+        filter_name: Option<&str>,
+        owner_account_id: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "search_filters", filter_name = ?filter_name, owner = ?owner_account_id);
+
+        let mut all_filters = Vec::new();
+        let mut start_at = 0usize;
+        const MAX_RESULTS: usize = 50;
+
+        loop {
+            let mut query_params = vec![
+                ("expand".into(), FILTER_EXPAND.to_string()),
+                ("maxResults".into(), MAX_RESULTS.to_string()),
+                ("startAt".into(), start_at.to_string()),
+            ];
+
+            if let Some(name) = filter_name {
+                query_params.push(("filterName".into(), name.to_string()));
+            }
+
+            if let Some(account_id) = owner_account_id {
+                query_params.push(("accountId".into(), account_id.to_string()));
+            }
+
+            let v = self.make_request(
+                reqwest::Method::GET,
+                "/rest/api/3/filter/search",
+                auth,
+                Some(query_params),
+                None,
+            ).await?;
+
+            let values = v
+                .get("values")
+                .and_then(|x| x.as_array())
+                .cloned()
+                .unwrap_or_default();
+
+            let is_last = v.get("isLast").and_then(|b| b.as_bool()).unwrap_or(true);
+
+            if values.is_empty() {
+                break;
+            }
+
+            all_filters.extend(values);
+
+            if is_last {
+                break;
+            }
+
+            start_at += MAX_RESULTS;
+        }
+
+        Ok(all_filters)
+    }
+
+    pub async fn create_filter(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "create_filter", payload = ?payload);
+
+        let query_params = vec![("expand".into(), FILTER_EXPAND.to_string())];
+
+        self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/filter",
+            auth,
+            Some(query_params),
+            Some(payload.clone()),
+        ).await
+    }
+
+    pub async fn update_filter(&self, filter_id: u64, payload: &Value, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "update_filter", filter_id = filter_id, payload = ?payload);
+
+        let query_params = vec![("expand".into(), FILTER_EXPAND.to_string())];
+
+        self.make_request(
+            reqwest::Method::PUT,
+            &format!("/rest/api/3/filter/{}", filter_id),
+            auth,
+            Some(query_params),
+            Some(payload.clone()),
+        ).await
+    }
+}
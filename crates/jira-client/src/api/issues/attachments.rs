@@ -0,0 +1,76 @@
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::auth::{apply_auth, Auth};
+use crate::api::{api_error, ApiClient};
+
+impl ApiClient {
+    pub async fn list_attachments(&self, issue_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "list_attachments", issue_key = %issue_key);
+        let response = self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/issue/{}", issue_key),
+            auth,
+            Some(vec![("fields".into(), "attachment".into())]),
+            None,
+        ).await?;
+
+        Ok(response
+            .get("fields")
+            .and_then(|f| f.get("attachment"))
+            .and_then(|a| a.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    pub async fn download_attachment(&self, attachment_id: &str, auth: &Auth) -> Result<Vec<u8>> {
+        tracing::info!(target: "jira", op = "download_attachment", attachment_id = %attachment_id);
+        let url = self
+            .base_url
+            .join(&format!("/rest/api/3/attachment/content/{}", attachment_id))?;
+
+        let resp = apply_auth(self.http.get(url), auth).send().await?;
+
+        if !resp.status().is_success() {
+            return Err(api_error(resp).await.into());
+        }
+
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    pub async fn add_attachment(
+        &self,
+        issue_key: &str,
+        filename: &str,
+        mime_type: Option<&str>,
+        content: Vec<u8>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "add_attachment", issue_key = %issue_key, filename = %filename, size = content.len());
+        let url = self
+            .base_url
+            .join(&format!("/rest/api/3/issue/{}/attachments", issue_key))?;
+
+        let mut part = reqwest::multipart::Part::bytes(content).file_name(filename.to_string());
+
+        if let Some(mime) = mime_type {
+            part = part.mime_str(mime)?;
+        }
+
+        let form = reqwest::multipart::Form::new().part("file", part);
+        let req = self
+            .http
+            .post(url)
+            .header("X-Atlassian-Token", "no-check")
+            .multipart(form);
+
+        let resp = apply_auth(req, auth).send().await?;
+
+        if !resp.status().is_success() {
+            return Err(api_error(resp).await.into());
+        }
+
+        let created: Value = resp.json().await?;
+        Ok(created.as_array().cloned().unwrap_or_default())
+    }
+}
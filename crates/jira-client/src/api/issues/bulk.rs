@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use crate::auth::Auth;
+use crate::error::JiraError;
+use crate::models::BulkCreateOutcome;
+use crate::api::ApiClient;
+
+/// Maximum number of issues Jira accepts in one bulk create request
+pub const BULK_CREATE_LIMIT: usize = 50;
+
+impl ApiClient {
+    /// Create issues in chunks of `BULK_CREATE_LIMIT`, returning one outcome per element
+    pub async fn bulk_create_issues(
+        &self,
+        issue_updates: &[Value],
+        auth: &Auth,
+    ) -> Result<Vec<BulkCreateOutcome>> {
+        tracing::info!(target: "jira", op = "bulk_create_issues", count = issue_updates.len());
+        let mut outcomes = Vec::with_capacity(issue_updates.len());
+
+        for chunk in issue_updates.chunks(BULK_CREATE_LIMIT) {
+            let body = json!({ "issueUpdates": chunk });
+
+            // Jira answers 400 when any element fails but still reports the created ones
+            let response = match self.make_request(
+                reqwest::Method::POST,
+                "/rest/api/3/issue/bulk",
+                auth,
+                None,
+                Some(body),
+            ).await {
+                Ok(response) => response,
+                Err(e) => match e.downcast::<JiraError>() {
+                    Ok(JiraError::ApiError { response, .. }) if has_element_errors(&response) => response,
+                    Ok(other) => return Err(other.into()),
+                    Err(e) => return Err(e),
+                },
+            };
+
+            outcomes.extend(align_bulk_response(&response, chunk.len()));
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Submit a bulk move; returns the id of the asynchronous task
+    pub async fn bulk_move_issues(&self, payload: &Value, auth: &Auth) -> Result<String> {
+        tracing::info!(target: "jira", op = "bulk_move_issues", payload = ?payload);
+        let response = self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/bulk/issues/move",
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await?;
+
+        response
+            .get("taskId")
+            .and_then(|t| t.as_str().map(|s| s.to_string()).or_else(|| t.as_u64().map(|n| n.to_string())))
+            .ok_or_else(|| anyhow::anyhow!("missing taskId in response"))
+    }
+
+    pub async fn get_bulk_task(&self, task_id: &str, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_bulk_task", task_id = %task_id);
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/bulk/queue/{}", task_id),
+            auth,
+            None,
+            None,
+        ).await
+    }
+}
+
+/// Whether a 400 body is a per-element bulk report rather than a plain request error;
+/// only the former carries an `errors` array with `failedElementNumber` entries
+fn has_element_errors(response: &Value) -> bool {
+    response
+        .get("errors")
+        .and_then(|e| e.as_array())
+        .is_some_and(|errors| errors.iter().any(|e| e.get("failedElementNumber").is_some()))
+}
+
+/// Match created issues and element errors back to their request positions
+fn align_bulk_response(response: &Value, len: usize) -> Vec<BulkCreateOutcome> {
+    let failures: HashMap<usize, &Value> = response
+        .get("errors")
+        .and_then(|e| e.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|e| {
+                    let idx = e.get("failedElementNumber").and_then(|n| n.as_u64())?;
+                    Some((idx as usize, e))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut created = response
+        .get("issues")
+        .and_then(|i| i.as_array())
+        .map(|arr| arr.iter())
+        .into_iter()
+        .flatten();
+
+    (0..len)
+        .map(|idx| {
+            if let Some(err) = failures.get(&idx) {
+                return BulkCreateOutcome {
+                    key: None,
+                    id: None,
+                    status: err.get("status").and_then(|s| s.as_u64()).map(|s| s as u16),
+                    errors: err.get("elementErrors").cloned(),
+                };
+            }
+
+            match created.next() {
+                Some(issue) => BulkCreateOutcome {
+                    key: issue.get("key").and_then(|k| k.as_str()).map(|k| k.to_string()),
+                    id: issue.get("id").and_then(|i| i.as_str()).map(|i| i.to_string()),
+                    status: None,
+                    errors: None,
+                },
+                None => BulkCreateOutcome {
+                    key: None,
+                    id: None,
+                    status: None,
+                    errors: Some(json!({ "errorMessages": ["Jira did not report a result for this element"] })),
+                },
+            }
+        })
+        .collect()
+}
@@ -41,6 +41,24 @@ impl ApiClient {
         ).await
     }
 
+    /// Add a comment whose body is already an ADF document
+    pub async fn add_comment_adf(
+        &self,
+        issue_key: &str,
+        body: &Value,
+        auth: &Auth,
+    ) -> Result<Value> {
+        tracing::info!(target: "jira", op = "add_comment_adf", issue_key = %issue_key);
+
+        self.make_request(
+            reqwest::Method::POST,
+            &format!("/rest/api/3/issue/{}/comment", issue_key),
+            auth,
+            None,
+            Some(json!({ "body": body })),
+        ).await
+    }
+
     pub async fn get_comments(
         &self,
         issue_key: &str,
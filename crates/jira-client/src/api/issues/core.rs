@@ -2,7 +2,7 @@ use anyhow::Result;
 use serde_json::Value;
 
 use crate::auth::Auth;
-use crate::models::{Issue, IssueDetail, IssueType};
+use crate::models::{Issue, IssueDetail, IssueType, SearchPage};
 use crate::utils::{adf_collect_text, normalize_whitespace, clean_value_recursive};
 use crate::api::ApiClient;
 
@@ -74,10 +74,23 @@ impl ApiClient {
     }
 
     pub async fn get_issue_detail(&self, key: &str, auth: &Auth) -> Result<IssueDetail> {
-        tracing::info!(target: "jira", op = "get_issue_detail", key = %key);
+        self.get_issue_detail_with(key, "*all", &[], auth).await
+    }
+
+    /// Issue detail limited to `fields` (comma-separated ids or `*all`), with extra `expand` entries
+    pub async fn get_issue_detail_with(
+        &self,
+        key: &str,
+        fields: &str,
+        expand: &[String],
+        auth: &Auth,
+    ) -> Result<IssueDetail> {
+        tracing::info!(target: "jira", op = "get_issue_detail", key = %key, fields = %fields, expand = ?expand);
+        let mut expand_param = vec!["schema".to_string(), "names".to_string()];
+        expand_param.extend(expand.iter().cloned());
         let query_params = vec![
-            ("fields".into(), "*all".into()),
-            ("expand".into(), "schema,names".into()),
+            ("fields".into(), fields.to_string()),
+            ("expand".into(), expand_param.join(",")),
         ];
         let v = self.make_request(
             reqwest::Method::GET,
@@ -193,15 +206,71 @@ impl ApiClient {
             .unwrap_or(key)
             .to_string();
         let url = self.base_url.join(&format!("/browse/{}", key))?.to_string();
+        let expanded: serde_json::Map<String, Value> = expand
+            .iter()
+            .filter_map(|name| v.get(name).map(|val| (name.clone(), val.clone())))
+            .collect();
         Ok(IssueDetail {
             key,
             url,
             summary,
             flagged,
             fields: mapped_fields,
+            expanded: (!expanded.is_empty()).then_some(Value::Object(expanded)),
+        })
+    }
+
+    /// Issue fields exactly as Jira returns them (ADF documents and all), for copying values back
+    pub async fn get_issue_raw(&self, key: &str, fields: &str, auth: &Auth) -> Result<Issue> {
+        tracing::info!(target: "jira", op = "get_issue_raw", key = %key, fields = %fields);
+        let v = self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/issue/{}", key),
+            auth,
+            Some(vec![("fields".into(), fields.to_string())]),
+            None,
+        ).await?;
+
+        Ok(Issue {
+            key: extract_string_field(&v, "key", key),
+            fields: v.get("fields").cloned().unwrap_or(Value::Object(Default::default())),
         })
     }
 
+    /// Full change history of an issue, oldest first; `expand=changelog` on search stops at 100 entries
+    pub async fn get_issue_changelog(&self, key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "get_issue_changelog", key = %key);
+
+        let mut histories = Vec::new();
+        let mut start_at = 0usize;
+        const MAX_RESULTS: usize = 100;
+
+        loop {
+            let v = self.make_request(
+                reqwest::Method::GET,
+                &format!("/rest/api/3/issue/{}/changelog", key),
+                auth,
+                Some(vec![
+                    ("startAt".into(), start_at.to_string()),
+                    ("maxResults".into(), MAX_RESULTS.to_string()),
+                ]),
+                None,
+            ).await?;
+
+            let values = v.get("values").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let count = values.len();
+            histories.extend(values);
+
+            let is_last = v.get("isLast").and_then(|l| l.as_bool()).unwrap_or(true);
+            if is_last || count == 0 {
+                break;
+            }
+            start_at += count;
+        }
+
+        Ok(histories)
+    }
+
     pub async fn get_issue_editmeta(&self, key: &str, auth: &Auth) -> Result<Value> {
         tracing::info!(target: "jira", op = "get_issue_editmeta", key = %key);
         self.make_request(
@@ -333,33 +402,62 @@ impl ApiClient {
         &self,
         jql: &str,
         fields: Option<&str>,
+        expand: Option<&str>,
         limit: usize,
-        start_at: Option<usize>,
+        next_page_token: Option<&str>,
         auth: &Auth,
-    ) -> Result<Vec<Value>> {
-        tracing::info!(target: "jira", op = "search_issues", jql = %jql, limit = limit, start_at = ?start_at, fields = ?fields);
+    ) -> Result<SearchPage> {
+        tracing::info!(target: "jira", op = "search_issues", jql = %jql, limit = limit, next_page_token = ?next_page_token, fields = ?fields, expand = ?expand);
         let fields_param = fields.unwrap_or("*all");
-        let mut query_params = vec![
-            ("jql".into(), jql.to_string()),
-            ("fields".into(), fields_param.to_string()),
-            ("maxResults".into(), limit.min(DEFAULT_PAGE_SIZE).to_string()),
-        ];
+        let mut token = next_page_token.map(|t| t.to_string());
+        let mut is_last = false;
+        let mut issues: Vec<Value> = Vec::new();
+        let mut names = serde_json::Map::new();
+        while issues.len() < limit {
+            let page_size = (limit - issues.len()).min(DEFAULT_PAGE_SIZE);
+            let mut query_params = vec![
+                ("jql".into(), jql.to_string()),
+                ("fields".into(), fields_param.to_string()),
+                ("maxResults".into(), page_size.to_string()),
+            ];
+
+            if let Some(exp) = expand {
+                query_params.push(("expand".into(), exp.to_string()));
+            }
+
+            if let Some(t) = &token {
+                query_params.push(("nextPageToken".into(), t.clone()));
+            }
+            let v = self.make_request(
+                reqwest::Method::GET,
+                "/rest/api/3/search/jql",
+                auth,
+                Some(query_params),
+                None,
+            ).await?;
+            let page = v
+                .get("issues")
+                .and_then(|x| x.as_array())
+                .cloned()
+                .unwrap_or_default();
+            token = v
+                .get("nextPageToken")
+                .and_then(|t| t.as_str())
+                .map(|t| t.to_string());
+            is_last = v
+                .get("isLast")
+                .and_then(|b| b.as_bool())
+                .unwrap_or(token.is_none());
+            if let Some(page_names) = v.get("names").and_then(|n| n.as_object()) {
+                names.extend(page_names.clone());
+            }
+            let page_empty = page.is_empty();
+            issues.extend(page);
 
-        if let Some(offset) = start_at {
-            query_params.push(("startAt".into(), offset.to_string()));
+            if is_last || token.is_none() || page_empty {
+                break;
+            }
         }
-        let v = self.make_request(
-            reqwest::Method::GET,
-            "/rest/api/3/search/jql",
-            auth,
-            Some(query_params),
-            None,
-        ).await?;
-        let mut issues = v
-            .get("issues")
-            .and_then(|x| x.as_array())
-            .cloned()
-            .unwrap_or_default();
         for issue in &mut issues {
             if let Some(fields_obj) = issue.get_mut("fields").and_then(|f| f.as_object_mut()) {
                 let null_keys: Vec<String> = fields_obj
@@ -380,7 +478,30 @@ impl ApiClient {
                 }
             }
         }
-        Ok(issues)
+
+        if is_last {
+            token = None;
+        }
+        Ok(SearchPage {
+            issues,
+            next_page_token: token,
+            is_last,
+            names,
+        })
+    }
+
+    pub async fn approximate_issue_count(&self, jql: &str, auth: &Auth) -> Result<u64> {
+        tracing::info!(target: "jira", op = "approximate_issue_count", jql = %jql);
+        let v = self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/search/approximate-count",
+            auth,
+            None,
+            Some(serde_json::json!({ "jql": jql })),
+        ).await?;
+        v.get("count")
+            .and_then(|c| c.as_u64())
+            .ok_or_else(|| anyhow::anyhow!("missing count in response"))
     }
 
     pub async fn list_issue_types(
@@ -69,32 +69,13 @@ impl ApiClient {
         tracing::info!(target: "jira", op = "list_labels", query = ?query, start_at = ?start_at, max_results = ?max_results);
 
         if let Some(q) = query {
-            let mut query_params: Vec<(String, String)> = vec![
-                ("fieldName".into(), "labels".into()),
-            ];
-
-            if !q.is_empty() {
-                query_params.push(("fieldValue".into(), q.to_string()));
-            }
-
-            let response: Value = self.make_request(
-                reqwest::Method::GET,
-                "/rest/api/3/jql/autocompletedata/suggestions",
-                auth,
-                Some(query_params),
-                None,
-            ).await?;
-
-            let labels: Vec<String> = response
-                .get("results")
-                .and_then(|r| r.as_array())
-                .map(|arr| {
-                    arr.iter()
-                        .filter_map(|item| item.get("value").and_then(|v| v.as_str()))
-                        .map(|s| s.to_string())
-                        .collect()
-                })
-                .unwrap_or_default();
+            let labels: Vec<String> = self
+                .get_jql_value_suggestions("labels", Some(q), auth)
+                .await?
+                .iter()
+                .filter_map(|item| item.get("value").and_then(|v| v.as_str()))
+                .map(|s| s.to_string())
+                .collect();
 
             return Ok(json!({
                 "labels": labels,
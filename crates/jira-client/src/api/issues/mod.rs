@@ -1,3 +1,5 @@
+mod attachments;
+mod bulk;
 mod core;
 mod comments;
 mod labels;
@@ -6,4 +8,5 @@ mod transitions;
 mod watchers;
 pub mod utils;
 
+pub use bulk::BULK_CREATE_LIMIT;
 pub use utils::{CommentVisibility, text_to_adf};
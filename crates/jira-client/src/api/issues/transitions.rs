@@ -105,4 +105,25 @@ impl ApiClient {
 
         Ok(())
     }
+
+    /// Workflows and statuses used by an issue type in a project (`workflows`, `statuses`)
+    pub async fn get_issue_workflow(
+        &self,
+        project_id: &str,
+        issue_type_id: &str,
+        auth: &Auth,
+    ) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_issue_workflow", project_id = %project_id, issue_type_id = %issue_type_id);
+        let payload = serde_json::json!({
+            "projectAndIssueTypes": [{ "projectId": project_id, "issueTypeId": issue_type_id }]
+        });
+
+        self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/workflows",
+            auth,
+            None,
+            Some(payload),
+        ).await
+    }
 }
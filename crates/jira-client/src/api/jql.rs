@@ -0,0 +1,69 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use crate::auth::Auth;
+use super::ApiClient;
+
+impl ApiClient {
+    pub async fn parse_jql(
+        &self,
+        queries: &[String],
+        validation: &str,
+        auth: &Auth,
+    ) -> Result<Value> {
+        tracing::info!(target: "jira", op = "parse_jql", count = queries.len(), validation = %validation);
+
+        let query_params = vec![("validation".into(), validation.to_string())];
+
+        self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/jql/parse",
+            auth,
+            Some(query_params),
+            Some(json!({ "queries": queries })),
+        ).await
+    }
+
+    pub async fn get_jql_autocomplete_data(&self, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_jql_autocomplete_data");
+
+        self.make_request(
+            reqwest::Method::GET,
+            "/rest/api/3/jql/autocompletedata",
+            auth,
+            None,
+            None,
+        ).await
+    }
+
+    pub async fn get_jql_value_suggestions(
+        &self,
+        field_name: &str,
+        field_value: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "get_jql_value_suggestions", field_name = %field_name, field_value = ?field_value);
+
+        let mut query_params: Vec<(String, String)> = vec![
+            ("fieldName".into(), field_name.to_string()),
+        ];
+
+        if let Some(v) = field_value.filter(|v| !v.is_empty()) {
+            query_params.push(("fieldValue".into(), v.to_string()));
+        }
+
+        let response: Value = self.make_request(
+            reqwest::Method::GET,
+            "/rest/api/3/jql/autocompletedata/suggestions",
+            auth,
+            Some(query_params),
+            None,
+        ).await?;
+
+        Ok(response
+            .get("results")
+            .and_then(|r| r.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+}
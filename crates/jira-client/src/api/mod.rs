@@ -5,7 +5,10 @@ use crate::auth::{apply_auth, Auth};
 use crate::error::JiraError;
 
 pub mod boards;
+pub mod fields;
+pub mod filters;
 pub mod issues;
+pub mod jql;
 pub mod projects;
 pub mod users;
 
@@ -46,14 +49,7 @@ impl ApiClient {
         let status = resp.status();
 
         if !status.is_success() {
-            let error_body = resp.text().await.unwrap_or_else(|_| "Failed to read error body".to_string());
-            let error_json: serde_json::Value = serde_json::from_str(&error_body)
-                .unwrap_or_else(|_| serde_json::json!({"raw_error": error_body}));
-
-            return Err(JiraError::ApiError {
-                status_code: status.as_u16(),
-                response: error_json,
-            }.into());
+            return Err(api_error(resp).await.into());
         }
 
         if status == reqwest::StatusCode::NO_CONTENT {
@@ -69,3 +65,16 @@ impl ApiClient {
         Ok(serde_json::from_str(&text)?)
     }
 }
+
+/// Turn a non-success response into a `JiraError::ApiError` carrying the parsed body
+pub(crate) async fn api_error(resp: reqwest::Response) -> JiraError {
+    let status = resp.status();
+    let error_body = resp.text().await.unwrap_or_else(|_| "Failed to read error body".to_string());
+    let error_json: serde_json::Value = serde_json::from_str(&error_body)
+        .unwrap_or_else(|_| serde_json::json!({"raw_error": error_body}));
+
+    JiraError::ApiError {
+        status_code: status.as_u16(),
+        response: error_json,
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use serde_json::Value;
 
 use crate::auth::Auth;
 use crate::models::{Project, ProjectSummary};
@@ -73,6 +74,12 @@ impl ApiClient {
 
             if let Some(values) = v.get("values").and_then(|v| v.as_array()) {
                 for project_val in values {
+                    let id = project_val
+                        .get("id")
+                        .and_then(|s| s.as_str())
+                        .unwrap_or("")
+                        .to_string();
+
                     let key = project_val
                         .get("key")
                         .and_then(|s| s.as_str())
@@ -86,7 +93,7 @@ impl ApiClient {
                         .to_string();
 
                     if !key.is_empty() && !name.is_empty() {
-                        all_projects.push(ProjectSummary { key, name });
+                        all_projects.push(ProjectSummary { id, key, name });
                     }
                 }
 
@@ -105,4 +112,233 @@ impl ApiClient {
 
         Ok(all_projects)
     }
+
+    /// Statuses available per issue type of a project
+    pub async fn get_project_statuses(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "get_project_statuses", project_key = %project_key);
+        let response = self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/project/{}/statuses", project_key),
+            auth,
+            None,
+            None,
+        ).await?;
+
+        Ok(response.as_array().cloned().unwrap_or_default())
+    }
+
+    /// All statuses visible to the user, across projects
+    pub async fn list_statuses(&self, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "list_statuses");
+        let response = self.make_request(
+            reqwest::Method::GET,
+            "/rest/api/3/status",
+            auth,
+            None,
+            None,
+        ).await?;
+
+        Ok(response.as_array().cloned().unwrap_or_default())
+    }
+
+    /// Components of a project, including lead and `issueCount`
+    pub async fn list_components(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "list_components", project_key = %project_key);
+
+        let mut all_components = Vec::new();
+        let mut start_at = 0usize;
+        const MAX_RESULTS: usize = 50;
+
+        loop {
+            let query_params = vec![
+                ("maxResults".into(), MAX_RESULTS.to_string()),
+                ("startAt".into(), start_at.to_string()),
+            ];
+
+            let v = self.make_request(
+                reqwest::Method::GET,
+                &format!("/rest/api/3/project/{}/component", project_key),
+                auth,
+                Some(query_params),
+                None,
+            ).await?;
+
+            let values = v.get("values").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            if values.is_empty() {
+                break;
+            }
+            all_components.extend(values);
+
+            let is_last = v.get("isLast").and_then(|l| l.as_bool()).unwrap_or(true);
+            if is_last {
+                break;
+            }
+
+            start_at += MAX_RESULTS;
+        }
+
+        Ok(all_components)
+    }
+
+    pub async fn get_component(&self, component_id: &str, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_component", component_id = %component_id);
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/component/{}", component_id),
+            auth,
+            None,
+            None,
+        ).await
+    }
+
+    pub async fn create_component(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "create_component", payload = ?payload);
+        self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/component",
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await
+    }
+
+    pub async fn update_component(&self, component_id: &str, payload: &Value, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "update_component", component_id = %component_id, payload = ?payload);
+        self.make_request(
+            reqwest::Method::PUT,
+            &format!("/rest/api/3/component/{}", component_id),
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await
+    }
+
+    /// Delete a component, moving its issues to `move_issues_to` (a component id) when given
+    pub async fn delete_component(
+        &self,
+        component_id: &str,
+        move_issues_to: Option<&str>,
+        auth: &Auth,
+    ) -> Result<()> {
+        tracing::info!(
+            target: "jira",
+            op = "delete_component",
+            component_id = %component_id,
+            move_issues_to = ?move_issues_to
+        );
+
+        let query_params = move_issues_to.map(|id| vec![("moveIssuesTo".to_string(), id.to_string())]);
+
+        self.make_request(
+            reqwest::Method::DELETE,
+            &format!("/rest/api/3/component/{}", component_id),
+            auth,
+            query_params,
+            None,
+        ).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_project(&self, project_key: &str, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_project", project_key = %project_key);
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/project/{}", project_key),
+            auth,
+            None,
+            None,
+        ).await
+    }
+
+    /// Versions of a project, optionally filtered by `status` (released, unreleased, archived),
+    /// with `issuesStatusForFixVersion` counts
+    pub async fn list_versions(&self, project_key: &str, status: Option<&str>, auth: &Auth) -> Result<Vec<Value>> {
+        tracing::info!(target: "jira", op = "list_versions", project_key = %project_key, status = ?status);
+
+        let mut all_versions = Vec::new();
+        let mut start_at = 0usize;
+        const MAX_RESULTS: usize = 50;
+
+        loop {
+            let mut query_params = vec![
+                ("expand".into(), "issuesstatus".into()),
+                ("orderBy".into(), "sequence".into()),
+                ("maxResults".into(), MAX_RESULTS.to_string()),
+                ("startAt".into(), start_at.to_string()),
+            ];
+            if let Some(s) = status {
+                query_params.push(("status".into(), s.to_string()));
+            }
+
+            let v = self.make_request(
+                reqwest::Method::GET,
+                &format!("/rest/api/3/project/{}/version", project_key),
+                auth,
+                Some(query_params),
+                None,
+            ).await?;
+
+            let values = v.get("values").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            if values.is_empty() {
+                break;
+            }
+            all_versions.extend(values);
+
+            let is_last = v.get("isLast").and_then(|l| l.as_bool()).unwrap_or(true);
+            if is_last {
+                break;
+            }
+
+            start_at += MAX_RESULTS;
+        }
+
+        Ok(all_versions)
+    }
+
+    pub async fn get_version(&self, version_id: &str, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_version", version_id = %version_id);
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/version/{}", version_id),
+            auth,
+            None,
+            None,
+        ).await
+    }
+
+    /// `issuesCount` and `issuesUnresolvedCount` of a version
+    pub async fn get_version_unresolved_count(&self, version_id: &str, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "get_version_unresolved_count", version_id = %version_id);
+        self.make_request(
+            reqwest::Method::GET,
+            &format!("/rest/api/3/version/{}/unresolvedIssueCount", version_id),
+            auth,
+            None,
+            None,
+        ).await
+    }
+
+    pub async fn create_version(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "create_version", payload = ?payload);
+        self.make_request(
+            reqwest::Method::POST,
+            "/rest/api/3/version",
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await
+    }
+
+    /// Partial update; also used to release and archive
+    pub async fn update_version(&self, version_id: &str, payload: &Value, auth: &Auth) -> Result<Value> {
+        tracing::info!(target: "jira", op = "update_version", version_id = %version_id, payload = ?payload);
+        self.make_request(
+            reqwest::Method::PUT,
+            &format!("/rest/api/3/version/{}", version_id),
+            auth,
+            None,
+            Some(payload.clone()),
+        ).await
+    }
 }
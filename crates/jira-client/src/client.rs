@@ -66,10 +66,28 @@ impl JiraClient {
         self.api_client.get_issue_detail(key, auth).await
     }
 
+    pub async fn get_issue_detail_with(
+        &self,
+        key: &str,
+        fields: &str,
+        expand: &[String],
+        auth: &Auth,
+    ) -> Result<IssueDetail> {
+        self.api_client.get_issue_detail_with(key, fields, expand, auth).await
+    }
+
+    pub async fn get_issue_raw(&self, key: &str, fields: &str, auth: &Auth) -> Result<Issue> {
+        self.api_client.get_issue_raw(key, fields, auth).await
+    }
+
     pub async fn get_issue_editmeta(&self, key: &str, auth: &Auth) -> Result<serde_json::Value> {
         self.api_client.get_issue_editmeta(key, auth).await
     }
 
+    pub async fn get_issue_changelog(&self, key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.get_issue_changelog(key, auth).await
+    }
+
     pub async fn search_issues_fields(
         &self,
         jql: &str,
@@ -94,11 +112,16 @@ impl JiraClient {
         &self,
         jql: &str,
         fields: Option<&str>,
+        expand: Option<&str>,
         limit: usize,
-        start_at: Option<usize>,
+        next_page_token: Option<&str>,
         auth: &Auth,
-    ) -> Result<Vec<serde_json::Value>> {
-        self.api_client.search_issues(jql, fields, limit, start_at, auth).await
+    ) -> Result<SearchPage> {
+        self.api_client.search_issues(jql, fields, expand, limit, next_page_token, auth).await
+    }
+
+    pub async fn approximate_issue_count(&self, jql: &str, auth: &Auth) -> Result<u64> {
+        self.api_client.approximate_issue_count(jql, auth).await
     }
 
     pub async fn list_issue_types(
@@ -197,6 +220,33 @@ impl JiraClient {
         self.api_client.get_board_issues(board_id, limit, auth).await
     }
 
+    pub async fn get_board(&self, board_id: u64, auth: &Auth) -> Result<Board> {
+        self.api_client.get_board(board_id, auth).await
+    }
+
+    pub async fn get_backlog(
+        &self,
+        board_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        start_at: usize,
+        max_results: usize,
+        auth: &Auth,
+    ) -> Result<Value> {
+        self.api_client.get_backlog(board_id, jql, fields, start_at, max_results, auth).await
+    }
+
+    pub async fn rank_issues(
+        &self,
+        issue_keys: &[String],
+        rank_before: Option<&str>,
+        rank_after: Option<&str>,
+        rank_field_id: Option<u64>,
+        auth: &Auth,
+    ) -> Result<Value> {
+        self.api_client.rank_issues(issue_keys, rank_before, rank_after, rank_field_id, auth).await
+    }
+
     pub async fn list_boards(
         &self,
         project_key: &str,
@@ -246,6 +296,60 @@ impl JiraClient {
         self.api_client.list_projects_summary(auth).await
     }
 
+    pub async fn get_project(&self, project_key: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_project(project_key, auth).await
+    }
+
+    pub async fn list_components(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_components(project_key, auth).await
+    }
+
+    pub async fn get_component(&self, component_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_component(component_id, auth).await
+    }
+
+    pub async fn create_component(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.create_component(payload, auth).await
+    }
+
+    pub async fn update_component(&self, component_id: &str, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.update_component(component_id, payload, auth).await
+    }
+
+    pub async fn delete_component(
+        &self,
+        component_id: &str,
+        move_issues_to: Option<&str>,
+        auth: &Auth,
+    ) -> Result<()> {
+        self.api_client.delete_component(component_id, move_issues_to, auth).await
+    }
+
+    pub async fn list_versions(
+        &self,
+        project_key: &str,
+        status: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.list_versions(project_key, status, auth).await
+    }
+
+    pub async fn get_version(&self, version_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_version(version_id, auth).await
+    }
+
+    pub async fn get_version_unresolved_count(&self, version_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_version_unresolved_count(version_id, auth).await
+    }
+
+    pub async fn create_version(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.create_version(payload, auth).await
+    }
+
+    pub async fn update_version(&self, version_id: &str, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.update_version(version_id, payload, auth).await
+    }
+
     pub async fn add_comment(
         &self,
         issue_key: &str,
@@ -256,6 +360,10 @@ impl JiraClient {
         self.api_client.add_comment(issue_key, body, visibility, auth).await
     }
 
+    pub async fn add_comment_adf(&self, issue_key: &str, body: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.add_comment_adf(issue_key, body, auth).await
+    }
+
     pub async fn move_issues_to_sprint(
         &self,
         sprint_id: u64,
@@ -347,6 +455,127 @@ impl JiraClient {
     ) -> Result<serde_json::Value> {
         self.api_client.list_labels(query, start_at, max_results, auth).await
     }
+
+    pub async fn list_fields(&self, auth: &Auth) -> Result<Vec<Field>> {
+        self.api_client.list_fields(auth).await
+    }
+
+    pub async fn parse_jql(&self, queries: &[String], validation: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.parse_jql(queries, validation, auth).await
+    }
+
+    pub async fn get_jql_autocomplete_data(&self, auth: &Auth) -> Result<Value> {
+        self.api_client.get_jql_autocomplete_data(auth).await
+    }
+
+    pub async fn get_jql_value_suggestions(
+        &self,
+        field_name: &str,
+        field_value: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.get_jql_value_suggestions(field_name, field_value, auth).await
+    }
+
+    pub async fn list_favourite_filters(&self, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_favourite_filters(auth).await
+    }
+
+    pub async fn search_filters(
+        &self,
+        filter_name: Option<&str>,
+        owner_account_id: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.search_filters(filter_name, owner_account_id, auth).await
+    }
+
+    pub async fn create_filter(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.create_filter(payload, auth).await
+    }
+
+    pub async fn update_filter(&self, filter_id: u64, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.update_filter(filter_id, payload, auth).await
+    }
+
+    pub async fn bulk_create_issues(
+        &self,
+        issue_updates: &[Value],
+        auth: &Auth,
+    ) -> Result<Vec<BulkCreateOutcome>> {
+        self.api_client.bulk_create_issues(issue_updates, auth).await
+    }
+
+    pub async fn list_attachments(&self, issue_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_attachments(issue_key, auth).await
+    }
+
+    pub async fn download_attachment(&self, attachment_id: &str, auth: &Auth) -> Result<Vec<u8>> {
+        self.api_client.download_attachment(attachment_id, auth).await
+    }
+
+    pub async fn add_attachment(
+        &self,
+        issue_key: &str,
+        filename: &str,
+        mime_type: Option<&str>,
+        content: Vec<u8>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.add_attachment(issue_key, filename, mime_type, content, auth).await
+    }
+
+    pub async fn get_project_statuses(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.get_project_statuses(project_key, auth).await
+    }
+
+    pub async fn bulk_move_issues(&self, payload: &Value, auth: &Auth) -> Result<String> {
+        self.api_client.bulk_move_issues(payload, auth).await
+    }
+
+    pub async fn get_bulk_task(&self, task_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_bulk_task(task_id, auth).await
+    }
+
+    pub async fn get_issue_workflow(
+        &self,
+        project_id: &str,
+        issue_type_id: &str,
+        auth: &Auth,
+    ) -> Result<Value> {
+        self.api_client.get_issue_workflow(project_id, issue_type_id, auth).await
+    }
+
+    pub async fn list_statuses(&self, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_statuses(auth).await
+    }
+
+    pub async fn get_sprint_issues(
+        &self,
+        sprint_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        limit: usize,
+        auth: &Auth,
+    ) -> Result<Vec<Issue>> {
+        self.api_client.get_sprint_issues(sprint_id, jql, fields, limit, auth).await
+    }
+
+    pub async fn create_sprint(&self, payload: &Value, auth: &Auth) -> Result<Sprint> {
+        self.api_client.create_sprint(payload, auth).await
+    }
+
+    pub async fn update_sprint(&self, sprint_id: u64, payload: &Value, auth: &Auth) -> Result<Sprint> {
+        self.api_client.update_sprint(sprint_id, payload, auth).await
+    }
+
+    pub async fn delete_sprint(&self, sprint_id: u64, auth: &Auth) -> Result<()> {
+        self.api_client.delete_sprint(sprint_id, auth).await
+    }
+
+    pub async fn get_velocity_chart(&self, board_id: u64, auth: &Auth) -> Result<Value> {
+        self.api_client.get_velocity_chart(board_id, auth).await
+    }
 }
 
 #[async_trait]
@@ -376,10 +605,28 @@ impl JiraApi for JiraClient {
         self.api_client.get_issue_detail(key, auth).await
     }
 
+    async fn get_issue_detail_with(
+        &self,
+        key: &str,
+        fields: &str,
+        expand: &[String],
+        auth: &Auth,
+    ) -> Result<IssueDetail> {
+        self.api_client.get_issue_detail_with(key, fields, expand, auth).await
+    }
+
+    async fn get_issue_raw(&self, key: &str, fields: &str, auth: &Auth) -> Result<Issue> {
+        self.api_client.get_issue_raw(key, fields, auth).await
+    }
+
     async fn get_issue_editmeta(&self, key: &str, auth: &Auth) -> Result<Value> {
         self.api_client.get_issue_editmeta(key, auth).await
     }
 
+    async fn get_issue_changelog(&self, key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.get_issue_changelog(key, auth).await
+    }
+
     async fn search_issues_fields(&self, jql: &str, limit: usize, auth: &Auth) -> Result<Vec<Issue>> {
         self.api_client.search_issues_fields(jql, limit, auth).await
     }
@@ -399,11 +646,16 @@ impl JiraApi for JiraClient {
         &self,
         jql: &str,
         fields: Option<&str>,
+        expand: Option<&str>,
         limit: usize,
-        start_at: Option<usize>,
+        next_page_token: Option<&str>,
         auth: &Auth,
-    ) -> Result<Vec<Value>> {
-        self.api_client.search_issues(jql, fields, limit, start_at, auth).await
+    ) -> Result<SearchPage> {
+        self.api_client.search_issues(jql, fields, expand, limit, next_page_token, auth).await
+    }
+
+    async fn approximate_issue_count(&self, jql: &str, auth: &Auth) -> Result<u64> {
+        self.api_client.approximate_issue_count(jql, auth).await
     }
 
     async fn list_issue_types(&self, project_key: Option<&str>, auth: &Auth) -> Result<Vec<IssueType>> {
@@ -439,6 +691,10 @@ impl JiraApi for JiraClient {
         self.api_client.add_comment(issue_key, body, visibility, auth).await
     }
 
+    async fn add_comment_adf(&self, issue_key: &str, body: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.add_comment_adf(issue_key, body, auth).await
+    }
+
     async fn get_comments(
         &self,
         issue_key: &str,
@@ -543,6 +799,33 @@ impl JiraApi for JiraClient {
         self.api_client.get_board_issues(board_id, limit, auth).await
     }
 
+    async fn get_board(&self, board_id: u64, auth: &Auth) -> Result<Board> {
+        self.api_client.get_board(board_id, auth).await
+    }
+
+    async fn get_backlog(
+        &self,
+        board_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        start_at: usize,
+        max_results: usize,
+        auth: &Auth,
+    ) -> Result<Value> {
+        self.api_client.get_backlog(board_id, jql, fields, start_at, max_results, auth).await
+    }
+
+    async fn rank_issues(
+        &self,
+        issue_keys: &[String],
+        rank_before: Option<&str>,
+        rank_after: Option<&str>,
+        rank_field_id: Option<u64>,
+        auth: &Auth,
+    ) -> Result<Value> {
+        self.api_client.rank_issues(issue_keys, rank_before, rank_after, rank_field_id, auth).await
+    }
+
     async fn get_myself(&self, auth: &Auth) -> Result<UserInfo> {
         self.api_client.get_myself(auth).await
     }
@@ -569,4 +852,170 @@ impl JiraApi for JiraClient {
     async fn list_projects_summary(&self, auth: &Auth) -> Result<Vec<ProjectSummary>> {
         self.api_client.list_projects_summary(auth).await
     }
+
+    async fn get_project(&self, project_key: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_project(project_key, auth).await
+    }
+
+    async fn list_components(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_components(project_key, auth).await
+    }
+
+    async fn get_component(&self, component_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_component(component_id, auth).await
+    }
+
+    async fn create_component(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.create_component(payload, auth).await
+    }
+
+    async fn update_component(&self, component_id: &str, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.update_component(component_id, payload, auth).await
+    }
+
+    async fn delete_component(
+        &self,
+        component_id: &str,
+        move_issues_to: Option<&str>,
+        auth: &Auth,
+    ) -> Result<()> {
+        self.api_client.delete_component(component_id, move_issues_to, auth).await
+    }
+
+    async fn list_versions(&self, project_key: &str, status: Option<&str>, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_versions(project_key, status, auth).await
+    }
+
+    async fn get_version(&self, version_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_version(version_id, auth).await
+    }
+
+    async fn get_version_unresolved_count(&self, version_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_version_unresolved_count(version_id, auth).await
+    }
+
+    async fn create_version(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.create_version(payload, auth).await
+    }
+
+    async fn update_version(&self, version_id: &str, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.update_version(version_id, payload, auth).await
+    }
+
+    async fn list_fields(&self, auth: &Auth) -> Result<Vec<Field>> {
+        self.api_client.list_fields(auth).await
+    }
+
+    async fn parse_jql(&self, queries: &[String], validation: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.parse_jql(queries, validation, auth).await
+    }
+
+    async fn get_jql_autocomplete_data(&self, auth: &Auth) -> Result<Value> {
+        self.api_client.get_jql_autocomplete_data(auth).await
+    }
+
+    async fn get_jql_value_suggestions(
+        &self,
+        field_name: &str,
+        field_value: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.get_jql_value_suggestions(field_name, field_value, auth).await
+    }
+
+    async fn list_favourite_filters(&self, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_favourite_filters(auth).await
+    }
+
+    async fn search_filters(
+        &self,
+        filter_name: Option<&str>,
+        owner_account_id: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.search_filters(filter_name, owner_account_id, auth).await
+    }
+
+    async fn create_filter(&self, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.create_filter(payload, auth).await
+    }
+
+    async fn update_filter(&self, filter_id: u64, payload: &Value, auth: &Auth) -> Result<Value> {
+        self.api_client.update_filter(filter_id, payload, auth).await
+    }
+
+    async fn bulk_create_issues(&self, issue_updates: &[Value], auth: &Auth) -> Result<Vec<BulkCreateOutcome>> {
+        self.api_client.bulk_create_issues(issue_updates, auth).await
+    }
+
+    async fn list_attachments(&self, issue_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_attachments(issue_key, auth).await
+    }
+
+    async fn download_attachment(&self, attachment_id: &str, auth: &Auth) -> Result<Vec<u8>> {
+        self.api_client.download_attachment(attachment_id, auth).await
+    }
+
+    async fn add_attachment(
+        &self,
+        issue_key: &str,
+        filename: &str,
+        mime_type: Option<&str>,
+        content: Vec<u8>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>> {
+        self.api_client.add_attachment(issue_key, filename, mime_type, content, auth).await
+    }
+
+    async fn get_project_statuses(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.get_project_statuses(project_key, auth).await
+    }
+
+    async fn bulk_move_issues(&self, payload: &Value, auth: &Auth) -> Result<String> {
+        self.api_client.bulk_move_issues(payload, auth).await
+    }
+
+    async fn get_bulk_task(&self, task_id: &str, auth: &Auth) -> Result<Value> {
+        self.api_client.get_bulk_task(task_id, auth).await
+    }
+
+    async fn get_issue_workflow(
+        &self,
+        project_id: &str,
+        issue_type_id: &str,
+        auth: &Auth,
+    ) -> Result<Value> {
+        self.api_client.get_issue_workflow(project_id, issue_type_id, auth).await
+    }
+
+    async fn list_statuses(&self, auth: &Auth) -> Result<Vec<Value>> {
+        self.api_client.list_statuses(auth).await
+    }
+
+    async fn get_sprint_issues(
+        &self,
+        sprint_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        limit: usize,
+        auth: &Auth,
+    ) -> Result<Vec<Issue>> {
+        self.api_client.get_sprint_issues(sprint_id, jql, fields, limit, auth).await
+    }
+
+    async fn create_sprint(&self, payload: &Value, auth: &Auth) -> Result<Sprint> {
+        self.api_client.create_sprint(payload, auth).await
+    }
+
+    async fn update_sprint(&self, sprint_id: u64, payload: &Value, auth: &Auth) -> Result<Sprint> {
+        self.api_client.update_sprint(sprint_id, payload, auth).await
+    }
+
+    async fn delete_sprint(&self, sprint_id: u64, auth: &Auth) -> Result<()> {
+        self.api_client.delete_sprint(sprint_id, auth).await
+    }
+
+    async fn get_velocity_chart(&self, board_id: u64, auth: &Auth) -> Result<Value> {
+        self.api_client.get_velocity_chart(board_id, auth).await
+    }
 }
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Field {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub custom: bool,
+    #[serde(default, rename = "clauseNames")]
+    pub clause_names: Vec<String>,
+    #[serde(default)]
+    pub schema: Option<serde_json::Value>,
+}
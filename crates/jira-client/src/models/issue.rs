@@ -13,6 +13,9 @@ pub struct IssueDetail {
     pub summary: Option<String>,
     pub flagged: bool,
     pub fields: serde_json::Value,
+    /// Requested expansions (renderedFields, changelog, transitions, operations) by name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expanded: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,3 +25,21 @@ pub struct IssueType {
     pub description: Option<String>,
     pub subtask: bool,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchPage {
+    pub issues: Vec<serde_json::Value>,
+    pub next_page_token: Option<String>,
+    pub is_last: bool,
+    #[serde(default)]
+    pub names: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Result of one element of a bulk create request, in request order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkCreateOutcome {
+    pub key: Option<String>,
+    pub id: Option<String>,
+    pub status: Option<u16>,
+    pub errors: Option<serde_json::Value>,
+}
@@ -3,9 +3,11 @@ pub mod user;
 pub mod board;
 pub mod project;
 pub mod sprint;
+pub mod field;
 
 pub use issue::*;
 pub use user::*;
 pub use board::*;
 pub use project::*;
 pub use sprint::*;
+pub use field::*;
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectSummary {
+    #[serde(default)]
+    pub id: String,
     pub key: String,
     pub name: String,
 }
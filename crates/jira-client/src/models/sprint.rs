@@ -9,6 +9,12 @@ pub struct Sprint {
     pub start_date: Option<String>,
     #[serde(rename = "endDate")]
     pub end_date: Option<String>,
+    #[serde(default, rename = "completeDate")]
+    pub complete_date: Option<String>,
+    #[serde(default, rename = "createdDate")]
+    pub created_date: Option<String>,
+    #[serde(default, rename = "originBoardId")]
+    pub origin_board_id: Option<u64>,
     #[serde(default)]
     pub goal: Option<String>,
 }
@@ -4,7 +4,7 @@ use serde_json::Value;
 
 use crate::api::issues::CommentVisibility;
 use crate::auth::Auth;
-use crate::models::{Board, Issue, IssueDetail, IssueType, Project, ProjectSummary, Sprint, UserInfo, UserSearchResult};
+use crate::models::{Board, BulkCreateOutcome, Field, Issue, IssueDetail, IssueType, Project, ProjectSummary, SearchPage, Sprint, UserInfo, UserSearchResult};
 
 /// Trait abstracting Jira API operations for testability
 #[async_trait]
@@ -26,8 +26,20 @@ pub trait JiraApi: Send + Sync + Clone {
 
     async fn get_issue_detail(&self, key: &str, auth: &Auth) -> Result<IssueDetail>;
 
+    async fn get_issue_detail_with(
+        &self,
+        key: &str,
+        fields: &str,
+        expand: &[String],
+        auth: &Auth,
+    ) -> Result<IssueDetail>;
+
+    async fn get_issue_raw(&self, key: &str, fields: &str, auth: &Auth) -> Result<Issue>;
+
     async fn get_issue_editmeta(&self, key: &str, auth: &Auth) -> Result<Value>;
 
+    async fn get_issue_changelog(&self, key: &str, auth: &Auth) -> Result<Vec<Value>>;
+
     async fn search_issues_fields(&self, jql: &str, limit: usize, auth: &Auth) -> Result<Vec<Issue>>;
 
     async fn get_recent_issues(
@@ -43,10 +55,13 @@ pub trait JiraApi: Send + Sync + Clone {
         &self,
         jql: &str,
         fields: Option<&str>,
+        expand: Option<&str>,
         limit: usize,
-        start_at: Option<usize>,
+        next_page_token: Option<&str>,
         auth: &Auth,
-    ) -> Result<Vec<Value>>;
+    ) -> Result<SearchPage>;
+
+    async fn approximate_issue_count(&self, jql: &str, auth: &Auth) -> Result<u64>;
 
     async fn list_issue_types(&self, project_key: Option<&str>, auth: &Auth) -> Result<Vec<IssueType>>;
 
@@ -73,6 +88,8 @@ pub trait JiraApi: Send + Sync + Clone {
         auth: &Auth,
     ) -> Result<Value>;
 
+    async fn add_comment_adf(&self, issue_key: &str, body: &Value, auth: &Auth) -> Result<Value>;
+
     async fn get_comments(
         &self,
         issue_key: &str,
@@ -141,6 +158,27 @@ pub trait JiraApi: Send + Sync + Clone {
 
     async fn get_board_issues(&self, board_id: u64, limit: usize, auth: &Auth) -> Result<Vec<Issue>>;
 
+    async fn get_board(&self, board_id: u64, auth: &Auth) -> Result<Board>;
+
+    async fn get_backlog(
+        &self,
+        board_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        start_at: usize,
+        max_results: usize,
+        auth: &Auth,
+    ) -> Result<Value>;
+
+    async fn rank_issues(
+        &self,
+        issue_keys: &[String],
+        rank_before: Option<&str>,
+        rank_after: Option<&str>,
+        rank_field_id: Option<u64>,
+        auth: &Auth,
+    ) -> Result<Value>;
+
     // User operations
     async fn get_myself(&self, auth: &Auth) -> Result<UserInfo>;
 
@@ -159,4 +197,115 @@ pub trait JiraApi: Send + Sync + Clone {
     async fn list_projects(&self, auth: &Auth) -> Result<Vec<Project>>;
 
     async fn list_projects_summary(&self, auth: &Auth) -> Result<Vec<ProjectSummary>>;
+
+    async fn get_project(&self, project_key: &str, auth: &Auth) -> Result<Value>;
+
+    // Component operations
+    async fn list_components(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>>;
+
+    async fn get_component(&self, component_id: &str, auth: &Auth) -> Result<Value>;
+
+    async fn create_component(&self, payload: &Value, auth: &Auth) -> Result<Value>;
+
+    async fn update_component(&self, component_id: &str, payload: &Value, auth: &Auth) -> Result<Value>;
+
+    async fn delete_component(
+        &self,
+        component_id: &str,
+        move_issues_to: Option<&str>,
+        auth: &Auth,
+    ) -> Result<()>;
+
+    // Version operations
+    async fn list_versions(&self, project_key: &str, status: Option<&str>, auth: &Auth) -> Result<Vec<Value>>;
+
+    async fn get_version(&self, version_id: &str, auth: &Auth) -> Result<Value>;
+
+    async fn get_version_unresolved_count(&self, version_id: &str, auth: &Auth) -> Result<Value>;
+
+    async fn create_version(&self, payload: &Value, auth: &Auth) -> Result<Value>;
+
+    async fn update_version(&self, version_id: &str, payload: &Value, auth: &Auth) -> Result<Value>;
+
+    // Field operations
+    async fn list_fields(&self, auth: &Auth) -> Result<Vec<Field>>;
+
+    // JQL operations
+    async fn parse_jql(&self, queries: &[String], validation: &str, auth: &Auth) -> Result<Value>;
+
+    async fn get_jql_autocomplete_data(&self, auth: &Auth) -> Result<Value>;
+
+    async fn get_jql_value_suggestions(
+        &self,
+        field_name: &str,
+        field_value: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>>;
+
+    // Filter operations
+    async fn list_favourite_filters(&self, auth: &Auth) -> Result<Vec<Value>>;
+
+    async fn search_filters(
+        &self,
+        filter_name: Option<&str>,
+        owner_account_id: Option<&str>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>>;
+
+    async fn create_filter(&self, payload: &Value, auth: &Auth) -> Result<Value>;
+
+    async fn update_filter(&self, filter_id: u64, payload: &Value, auth: &Auth) -> Result<Value>;
+
+    // Bulk issue operations
+    async fn bulk_create_issues(&self, issue_updates: &[Value], auth: &Auth) -> Result<Vec<BulkCreateOutcome>>;
+
+    // Attachment operations
+    async fn list_attachments(&self, issue_key: &str, auth: &Auth) -> Result<Vec<Value>>;
+
+    async fn download_attachment(&self, attachment_id: &str, auth: &Auth) -> Result<Vec<u8>>;
+
+    async fn add_attachment(
+        &self,
+        issue_key: &str,
+        filename: &str,
+        mime_type: Option<&str>,
+        content: Vec<u8>,
+        auth: &Auth,
+    ) -> Result<Vec<Value>>;
+
+    // Move operations
+    async fn get_project_statuses(&self, project_key: &str, auth: &Auth) -> Result<Vec<Value>>;
+
+    async fn bulk_move_issues(&self, payload: &Value, auth: &Auth) -> Result<String>;
+
+    async fn get_bulk_task(&self, task_id: &str, auth: &Auth) -> Result<Value>;
+
+    // Workflow operations
+    async fn get_issue_workflow(
+        &self,
+        project_id: &str,
+        issue_type_id: &str,
+        auth: &Auth,
+    ) -> Result<Value>;
+
+    async fn list_statuses(&self, auth: &Auth) -> Result<Vec<Value>>;
+
+    // Sprint lifecycle
+    async fn get_sprint_issues(
+        &self,
+        sprint_id: u64,
+        jql: Option<&str>,
+        fields: &str,
+        limit: usize,
+        auth: &Auth,
+    ) -> Result<Vec<Issue>>;
+
+    async fn create_sprint(&self, payload: &Value, auth: &Auth) -> Result<Sprint>;
+
+    async fn update_sprint(&self, sprint_id: u64, payload: &Value, auth: &Auth) -> Result<Sprint>;
+
+    async fn delete_sprint(&self, sprint_id: u64, auth: &Auth) -> Result<()>;
+
+    // Board reports
+    async fn get_velocity_chart(&self, board_id: u64, auth: &Auth) -> Result<Value>;
 }
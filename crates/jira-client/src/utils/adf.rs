@@ -63,3 +63,170 @@ pub fn normalize_whitespace(mut s: String) -> String {
     }
     out.trim().to_string()
 }
+
+/// Convert a small Markdown subset (headings, bullet/numbered lists, fenced code,
+/// paragraphs, `**bold**`, `*italic*` and `` `code` ``) into an ADF document
+pub fn markdown_to_adf(markdown: &str) -> serde_json::Value {
+    let mut content: Vec<serde_json::Value> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<(&str, Vec<serde_json::Value>)> = None;
+    let mut code: Option<(String, Vec<&str>)> = None;
+
+    fn flush_paragraph(lines: &mut Vec<&str>, content: &mut Vec<serde_json::Value>) {
+        if lines.is_empty() {
+            return;
+        }
+
+        let mut inline = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                inline.push(serde_json::json!({ "type": "hardBreak" }));
+            }
+            inline.extend(markdown_inline(line.trim()));
+        }
+
+        content.push(serde_json::json!({ "type": "paragraph", "content": inline }));
+        lines.clear();
+    }
+
+    fn flush_list(list: &mut Option<(&str, Vec<serde_json::Value>)>, content: &mut Vec<serde_json::Value>) {
+        if let Some((kind, items)) = list.take() {
+            content.push(serde_json::json!({ "type": kind, "content": items }));
+        }
+    }
+
+    for line in markdown.lines() {
+        if let Some((language, lines)) = code.as_mut() {
+            if line.trim_start().starts_with("```") {
+                let mut block = serde_json::json!({
+                    "type": "codeBlock",
+                    "content": [{ "type": "text", "text": lines.join("\n") }]
+                });
+                if !language.is_empty() {
+                    block["attrs"] = serde_json::json!({ "language": language });
+                }
+                if lines.is_empty() {
+                    block["content"] = serde_json::json!([]);
+                }
+                content.push(block);
+                code = None;
+            } else {
+                lines.push(line);
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            flush_paragraph(&mut paragraph, &mut content);
+            flush_list(&mut list, &mut content);
+            code = Some((language.trim().to_string(), Vec::new()));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut content);
+            flush_list(&mut list, &mut content);
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            flush_paragraph(&mut paragraph, &mut content);
+            flush_list(&mut list, &mut content);
+            content.push(serde_json::json!({
+                "type": "heading",
+                "attrs": { "level": level },
+                "content": markdown_inline(trimmed[level..].trim())
+            }));
+            continue;
+        }
+
+        let bullet = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+            .map(|rest| ("bulletList", rest));
+        let ordered = || {
+            let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+            (digits > 0)
+                .then(|| trimmed[digits..].strip_prefix(". "))
+                .flatten()
+                .map(|rest| ("orderedList", rest))
+        };
+
+        if let Some((kind, rest)) = bullet.or_else(ordered) {
+            flush_paragraph(&mut paragraph, &mut content);
+
+            if list.as_ref().map(|(k, _)| *k != kind).unwrap_or(false) {
+                flush_list(&mut list, &mut content);
+            }
+
+            let item = serde_json::json!({
+                "type": "listItem",
+                "content": [{ "type": "paragraph", "content": markdown_inline(rest.trim()) }]
+            });
+            list.get_or_insert((kind, Vec::new())).1.push(item);
+            continue;
+        }
+
+        flush_list(&mut list, &mut content);
+        paragraph.push(line);
+    }
+
+    if let Some((_, lines)) = code {
+        content.push(serde_json::json!({
+            "type": "codeBlock",
+            "content": [{ "type": "text", "text": lines.join("\n") }]
+        }));
+    }
+    flush_paragraph(&mut paragraph, &mut content);
+    flush_list(&mut list, &mut content);
+
+    serde_json::json!({ "type": "doc", "version": 1, "content": content })
+}
+
+/// Split a line into ADF text nodes, applying `**strong**`, `*em*` and `` `code` `` marks
+fn markdown_inline(text: &str) -> Vec<serde_json::Value> {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+
+    let text_node = |t: &str, mark: Option<&str>| {
+        let mut node = serde_json::json!({ "type": "text", "text": t });
+        if let Some(mark) = mark {
+            node["marks"] = serde_json::json!([{ "type": mark }]);
+        }
+        node
+    };
+
+    while !rest.is_empty() {
+        let next = ["**", "`", "*"]
+            .iter()
+            .filter_map(|delim| {
+                let start = rest.find(delim)?;
+                let end = rest[start + delim.len()..].find(delim)?;
+                (end > 0).then_some((start, end, *delim))
+            })
+            .min_by_key(|(start, _, delim)| (*start, usize::MAX - delim.len()));
+
+        let Some((start, len, delim)) = next else {
+            nodes.push(text_node(rest, None));
+            break;
+        };
+
+        if start > 0 {
+            nodes.push(text_node(&rest[..start], None));
+        }
+
+        let inner = &rest[start + delim.len()..start + delim.len() + len];
+        let mark = match delim {
+            "**" => "strong",
+            "`" => "code",
+            _ => "em",
+        };
+        nodes.push(text_node(inner, Some(mark)));
+        rest = &rest[start + delim.len() * 2 + len..];
+    }
+
+    nodes
+}
@@ -1,12 +1,48 @@
 use std::collections::HashSet;
 
 use super::context::JiraCtx;
-use super::errors::log_err;
+use super::errors::{describe_error, log_err};
 
+/// The field a board estimates with (story points, original estimate, ...); `None` for issue-count boards
+pub fn board_estimation_field(cfg: &serde_json::Value) -> Option<String> {
+    cfg.get("estimation")
+        .and_then(|e| e.get("field"))
+        .and_then(|f| f.get("fieldId"))
+        .and_then(|s| s.as_str())
+        .map(|s| s.to_string())
+}
+
+/// Board for a project: the configured default, else its first scrum or kanban board
+pub async fn resolve_project_board(ctx: &JiraCtx, project_key: &str) -> Option<u64> {
+    if let Some(board_id) = ctx.settings.default_boards.get(project_key) {
+        return Some(*board_id);
+    }
+
+    match ctx.client.list_boards(project_key, &ctx.auth).await {
+        Ok(boards) => boards
+            .iter()
+            .find(|b| b.type_.eq_ignore_ascii_case("scrum") || b.type_.eq_ignore_ascii_case("kanban"))
+            .map(|b| b.id),
+        Err(e) => {
+            tracing::warn!(
+                target: "mcp",
+                project_key = %project_key,
+                error = %describe_error(&e),
+                "Could not list boards for project"
+            );
+            None
+        }
+    }
+}
+
+/// Fields to show for an issue: editable and creatable fields, the board's estimation field
+/// when a board is given, and a fixed core set. A board the caller asked for must load; an
+/// `inferred` one that fails is logged and left out.
 pub async fn compute_board_field_keys(
     ctx: &JiraCtx,
     issue: &jira_client::models::issue::IssueDetail,
-    board_id: u64,
+    board_id: Option<u64>,
+    inferred: bool,
 ) -> Result<HashSet<String>, rmcp::ErrorData> {
     let project_key = issue
         .fields
@@ -21,13 +57,30 @@ pub async fn compute_board_field_keys(
         .and_then(|s| s.as_str());
 
     let (cfg_result, editmeta_result, createmeta_result) = tokio::join!(
-        ctx.client.get_board_configuration(board_id, &ctx.auth),
+        async {
+            match board_id {
+                Some(id) => ctx.client.get_board_configuration(id, &ctx.auth).await.map(Some),
+                None => Ok(None),
+            }
+        },
         ctx.client.get_issue_editmeta(&issue.key, &ctx.auth),
         ctx.client.get_createmeta(project_key, issue_type, &ctx.auth),
     );
 
-    let cfg = cfg_result
-        .map_err(|e| log_err("get_issue", "jira_error", e.to_string()))?;
+    let cfg = match cfg_result {
+        Ok(cfg) => cfg,
+        Err(e) if inferred => {
+            tracing::warn!(
+                target: "mcp",
+                tool = "get_issue",
+                board_id = ?board_id,
+                error = %describe_error(&e),
+                "Could not load inferred board configuration, skipping estimation field"
+            );
+            None
+        }
+        Err(e) => return Err(log_err("get_issue", "jira_error", e.to_string())),
+    };
     let editmeta = editmeta_result
         .map_err(|e| log_err("get_issue", "jira_error", e.to_string()))?;
     let createmeta = createmeta_result
@@ -53,16 +106,11 @@ pub async fn compute_board_field_keys(
         }
     }
 
-    let estimation_field = cfg
-        .get("estimation")
-        .and_then(|e| e.get("field"))
-        .and_then(|f| f.get("fieldId"))
-        .and_then(|s| s.as_str())
-        .map(|s| s.to_string());
+    let estimation_field = cfg.as_ref().and_then(board_estimation_field);
 
     let mut keys: HashSet<String> = edit_keys.into_iter().collect();
 
-    keys.extend(create_keys.into_iter());
+    keys.extend(create_keys);
 
     if let Some(f) = estimation_field {
         keys.insert(f);
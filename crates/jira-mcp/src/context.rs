@@ -1,15 +1,21 @@
+use std::sync::Arc;
+
 use jira_client::{auth::Auth, client::JiraClient, config::JiraConfig};
 
 use super::errors::log_err;
+use super::metadata_cache::MetadataCache;
+use super::settings::Settings;
 
 #[derive(Clone)]
 pub struct JiraCtx {
     pub auth: Auth,
     pub client: JiraClient,
+    pub cache: MetadataCache,
+    pub settings: Arc<Settings>,
 }
 
 impl JiraCtx {
-    pub fn from_config(config: &JiraConfig) -> Result<Self, rmcp::ErrorData> {
+    pub fn from_config(config: &JiraConfig, settings: Settings) -> Result<Self, rmcp::ErrorData> {
         tracing::info!(target: "mcp", base_url = %config.jira_base_url, "Creating Jira context");
 
         let auth = config.create_auth();
@@ -19,6 +25,11 @@ impl JiraCtx {
         })?;
 
         tracing::info!(target: "mcp", "Jira context initialized");
-        Ok(JiraCtx { auth, client })
+        Ok(JiraCtx {
+            auth,
+            client,
+            cache: MetadataCache::default(),
+            settings: Arc::new(settings),
+        })
     }
 }
@@ -2,6 +2,7 @@ mod types;
 pub mod suggestions;
 
 pub use types::{
+    describe_error,
     extract_error_message,
     log_err,
     HandlerErrorContext,
@@ -208,3 +208,94 @@ pub fn get_link_suggestions(status_code: u16) -> Vec<String> {
     }
     suggestions
 }
+
+pub fn get_filter_suggestions(status_code: u16) -> Vec<String> {
+    let mut suggestions = Vec::new();
+
+    match status_code {
+        400 => {
+            suggestions.push("Check the filter JQL with validate_jql".to_string());
+            suggestions.push("Filter names must be unique for the owner".to_string());
+            suggestions.push("Verify share permission projects, groups and users exist".to_string());
+        }
+        403 => {
+            suggestions.push("You don't have permission to view or edit this filter".to_string());
+            suggestions.push("Only the filter owner can update it".to_string());
+        }
+        404 => {
+            suggestions.push("Filter not found".to_string());
+            suggestions.push("Use list_filters to see available filters".to_string());
+        }
+        _ => {
+            suggestions.push("Check your permissions and filter id".to_string());
+        }
+    }
+    suggestions
+}
+
+pub fn get_sprint_suggestions(status_code: u16) -> Vec<String> {
+    let mut suggestions = Vec::new();
+
+    match status_code {
+        400 => {
+            suggestions.push("Dates must be ISO 8601 and the end date after the start date".to_string());
+            suggestions.push("Only one sprint per board can be active unless parallel sprints are enabled".to_string());
+            suggestions.push("Closed sprints cannot be changed".to_string());
+        }
+        403 => {
+            suggestions.push("Managing sprints needs the 'Manage Sprints' permission on the board's projects".to_string());
+        }
+        404 => {
+            suggestions.push("Sprint or board not found".to_string());
+            suggestions.push("Use list_boards and list_sprints to find valid ids".to_string());
+        }
+        _ => {
+            suggestions.push("Check your permissions and sprint id".to_string());
+        }
+    }
+    suggestions
+}
+
+pub fn get_component_suggestions(status_code: u16) -> Vec<String> {
+    let mut suggestions = Vec::new();
+
+    match status_code {
+        400 => {
+            suggestions.push("Component names must be unique within the project".to_string());
+            suggestions.push("The lead must be an active user with access to the project".to_string());
+        }
+        403 => {
+            suggestions.push("Managing components needs the 'Administer Projects' permission".to_string());
+        }
+        404 => {
+            suggestions.push("Project or component not found".to_string());
+            suggestions.push("Use list_components to find valid component ids".to_string());
+        }
+        _ => {
+            suggestions.push("Check your permissions and component id".to_string());
+        }
+    }
+    suggestions
+}
+
+pub fn get_version_suggestions(status_code: u16) -> Vec<String> {
+    let mut suggestions = Vec::new();
+
+    match status_code {
+        400 => {
+            suggestions.push("Version names must be unique within the project".to_string());
+            suggestions.push("Dates must be YYYY-MM-DD and the release date not before the start date".to_string());
+        }
+        403 => {
+            suggestions.push("Managing versions needs the 'Administer Projects' permission".to_string());
+        }
+        404 => {
+            suggestions.push("Project or version not found".to_string());
+            suggestions.push("Use list_versions to find valid version ids".to_string());
+        }
+        _ => {
+            suggestions.push("Check your permissions and version id".to_string());
+        }
+    }
+    suggestions
+}
@@ -37,6 +37,16 @@ pub fn extract_error_message(response: &Value) -> String {
     response.to_string()
 }
 
+/// One-line description of a client error, using Jira's own message for API errors
+pub fn describe_error(e: &anyhow::Error) -> String {
+    match e.downcast_ref::<JiraError>() {
+        Some(JiraError::ApiError { status_code, response }) => {
+            format!("Jira API Error ({}): {}", status_code, extract_error_message(response))
+        }
+        _ => e.to_string(),
+    }
+}
+
 /// Context for error handling with suggestions and metadata
 pub struct HandlerErrorContext {
     pub tool_name: &'static str,
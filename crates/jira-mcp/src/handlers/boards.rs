@@ -0,0 +1,478 @@
+use std::collections::{BTreeMap, HashMap};
+
+use jira_client::models::Issue;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Value};
+
+use crate::board_utils::board_estimation_field;
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::suggestions::get_sprint_suggestions;
+use crate::errors::{describe_error, ResultMcpExt};
+use crate::models::{BoardSwimlane, GetBacklogInput, GetBoardInput, RankIssuesInput};
+
+const BOARD_ISSUE_FIELDS: &str = "summary,status,assignee,issuetype,priority,parent";
+/// Jira ranks at most 50 issues per request
+const RANK_BATCH_SIZE: usize = 50;
+
+/// A board column from `columnConfig`, with the issues mapped onto it
+struct BoardColumn {
+    name: String,
+    status_ids: Vec<String>,
+    min: Option<u64>,
+    max: Option<u64>,
+    issues: Vec<Value>,
+}
+
+fn issue_summary(issue: &Issue) -> Value {
+    let f = &issue.fields;
+    json!({
+        "key": issue.key,
+        "summary": f.get("summary"),
+        "status": f.get("status").and_then(|s| s.get("name")),
+        "issue_type": f.get("issuetype").and_then(|t| t.get("name")),
+        "assignee": f.get("assignee").and_then(|a| a.get("displayName")),
+        "priority": f.get("priority").and_then(|p| p.get("name")),
+        "parent": f.get("parent").and_then(|p| p.get("key")),
+        "subtask": f.get("issuetype").and_then(|t| t.get("subtask")).and_then(|s| s.as_bool()).unwrap_or(false)
+    })
+}
+
+fn swimlane_name(issue: &Value, swimlane: BoardSwimlane) -> String {
+    let key = match swimlane {
+        BoardSwimlane::Assignee => "assignee",
+        BoardSwimlane::Parent => "parent",
+        BoardSwimlane::Priority => "priority",
+    };
+
+    issue
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| match swimlane {
+            BoardSwimlane::Assignee => "Unassigned".to_string(),
+            BoardSwimlane::Parent => "No parent".to_string(),
+            BoardSwimlane::Priority => "No priority".to_string(),
+        })
+}
+
+pub async fn get_board_handler(
+    input: GetBoardInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "get_board",
+        board_id = input.board_id,
+        swimlane = ?input.swimlane,
+        "Getting board view"
+    );
+
+    let (board_result, cfg_result) = tokio::join!(
+        ctx.client.get_board(input.board_id, &ctx.auth),
+        ctx.client.get_board_configuration(input.board_id, &ctx.auth),
+    );
+
+    let board = board_result.mcp_context(
+        error_ctx!("get_board", "get board")
+            .with_metadata("board_id", input.board_id.to_string())
+            .with_suggestions(get_sprint_suggestions)
+    )?;
+    let cfg = cfg_result.mcp_context(
+        error_ctx!("get_board", "get board configuration")
+            .with_metadata("board_id", input.board_id.to_string())
+    )?;
+
+    let column_config = cfg.get("columnConfig").cloned().unwrap_or(Value::Null);
+    let constraint = column_config
+        .get("constraintType")
+        .and_then(|c| c.as_str())
+        .unwrap_or("none")
+        .to_string();
+
+    let mut columns: Vec<BoardColumn> = column_config
+        .get("columns")
+        .and_then(|c| c.as_array())
+        .map(|cols| {
+            cols.iter()
+                .map(|c| BoardColumn {
+                    name: c.get("name").and_then(|n| n.as_str()).unwrap_or_default().to_string(),
+                    status_ids: c
+                        .get("statuses")
+                        .and_then(|s| s.as_array())
+                        .map(|s| {
+                            s.iter()
+                                .filter_map(|st| st.get("id").and_then(|id| id.as_str()))
+                                .map(|id| id.to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    min: c.get("min").and_then(|m| m.as_u64()),
+                    max: c.get("max").and_then(|m| m.as_u64()),
+                    issues: Vec::new(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Scrum boards show the active sprint(s); kanban boards show the board filter
+    let is_scrum = board.type_.eq_ignore_ascii_case("scrum");
+    let mut sprints = Vec::new();
+    let issues: Vec<Issue> = if is_scrum {
+        sprints = ctx
+            .client
+            .list_sprints(input.board_id, Some("active"), &ctx.auth)
+            .await
+            .mcp_context(
+                error_ctx!("get_board", "list active sprints")
+                    .with_metadata("board_id", input.board_id.to_string())
+            )?;
+
+        let mut all = Vec::new();
+        for sprint in &sprints {
+            let remaining = input.limit.saturating_sub(all.len());
+            if remaining == 0 {
+                break;
+            }
+            let page = ctx
+                .client
+                .get_sprint_issues(sprint.id, None, BOARD_ISSUE_FIELDS, remaining, &ctx.auth)
+                .await
+                .mcp_context(
+                    error_ctx!("get_board", "get sprint issues")
+                        .with_metadata("sprint_id", sprint.id.to_string())
+                )?;
+            all.extend(page);
+        }
+        all
+    } else {
+        ctx.client
+            .get_board_issues(input.board_id, input.limit, &ctx.auth)
+            .await
+            .mcp_context(
+                error_ctx!("get_board", "get board issues")
+                    .with_metadata("board_id", input.board_id.to_string())
+            )?
+    };
+
+    let status_names: HashMap<String, String> = match ctx.client.list_statuses(&ctx.auth).await {
+        Ok(statuses) => statuses
+            .iter()
+            .filter_map(|s| Some((s.get("id")?.as_str()?.to_string(), s.get("name")?.as_str()?.to_string())))
+            .collect(),
+        Err(e) => {
+            tracing::warn!(target: "mcp", tool = "get_board", error = %describe_error(&e), "Could not load status names");
+            HashMap::new()
+        }
+    };
+
+    let mut unmapped = Vec::new();
+    for issue in &issues {
+        let status_id = issue
+            .fields
+            .get("status")
+            .and_then(|s| s.get("id"))
+            .and_then(|id| id.as_str())
+            .unwrap_or_default();
+
+        match columns.iter_mut().find(|c| c.status_ids.iter().any(|s| s == status_id)) {
+            Some(column) => column.issues.push(issue_summary(issue)),
+            None => unmapped.push(issue_summary(issue)),
+        }
+    }
+
+    let mut violations = Vec::new();
+    let columns_out: Vec<Value> = columns
+        .into_iter()
+        .map(|column| {
+            let wip_count = if constraint == "issueCountExclSubs" {
+                column
+                    .issues
+                    .iter()
+                    .filter(|i| i.get("subtask").and_then(|s| s.as_bool()) != Some(true))
+                    .count()
+            } else {
+                column.issues.len()
+            };
+
+            let wip = if constraint == "none" {
+                None
+            } else if column.max.is_some_and(|max| wip_count as u64 > max) {
+                Some("over_max")
+            } else if column.min.is_some_and(|min| (wip_count as u64) < min) {
+                Some("under_min")
+            } else {
+                None
+            };
+
+            if let Some(kind) = wip {
+                violations.push(json!({
+                    "column": column.name,
+                    "violation": kind,
+                    "count": wip_count,
+                    "min": column.min,
+                    "max": column.max
+                }));
+            }
+
+            let statuses: Vec<String> = column
+                .status_ids
+                .iter()
+                .map(|id| status_names.get(id).cloned().unwrap_or_else(|| id.clone()))
+                .collect();
+
+            let mut out = json!({
+                "name": column.name,
+                "statuses": statuses,
+                "min": column.min,
+                "max": column.max,
+                "count": column.issues.len(),
+                "wip_count": wip_count,
+                "wip_violation": wip
+            });
+
+            match input.swimlane {
+                Some(swimlane) => {
+                    let mut lanes: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+                    for issue in column.issues {
+                        lanes.entry(swimlane_name(&issue, swimlane)).or_default().push(issue);
+                    }
+                    out["swimlanes"] = json!(lanes);
+                }
+                None => out["issues"] = json!(column.issues),
+            }
+
+            out
+        })
+        .collect();
+
+    tracing::info!(
+        target: "mcp",
+        tool = "get_board",
+        board_id = input.board_id,
+        issues = issues.len(),
+        violations = violations.len(),
+        "Board view built"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "board": board,
+        "active_sprints": sprints,
+        "wip_constraint": constraint,
+        "columns": columns_out,
+        "wip_violations": violations,
+        "unmapped_issues": unmapped,
+        "total_issues": issues.len(),
+        "truncated": issues.len() >= input.limit
+    })))
+}
+
+pub async fn get_backlog_handler(
+    input: GetBacklogInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "get_backlog",
+        board_id = input.board_id,
+        start_at = input.start_at,
+        limit = input.limit,
+        "Getting backlog"
+    );
+
+    let cfg = ctx
+        .client
+        .get_board_configuration(input.board_id, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("get_backlog", "get board configuration")
+                .with_metadata("board_id", input.board_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+    let estimation_field = board_estimation_field(&cfg);
+
+    let mut fields = BOARD_ISSUE_FIELDS.to_string();
+    if let Some(field) = &estimation_field {
+        fields.push(',');
+        fields.push_str(field);
+    }
+
+    let page = ctx
+        .client
+        .get_backlog(input.board_id, input.jql.as_deref(), &fields, input.start_at, input.limit.min(100), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("get_backlog", "get backlog")
+                .with_metadata("board_id", input.board_id.to_string())
+                .with_metadata("jql", input.jql.clone().unwrap_or_default())
+        )?;
+
+    let issues: Vec<Value> = page
+        .get("issues")
+        .and_then(|i| i.as_array())
+        .map(|arr| {
+            arr.iter()
+                .enumerate()
+                .map(|(index, it)| {
+                    let issue = Issue {
+                        key: it.get("key").and_then(|k| k.as_str()).unwrap_or_default().to_string(),
+                        fields: it.get("fields").cloned().unwrap_or_default(),
+                    };
+                    let mut out = issue_summary(&issue);
+                    out["rank"] = json!(input.start_at + index + 1);
+                    if let Some(field) = &estimation_field {
+                        out["estimate"] = issue.fields.get(field).cloned().unwrap_or(Value::Null);
+                    }
+                    out
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let total = page.get("total").and_then(|t| t.as_u64()).unwrap_or(0) as usize;
+    let next_start_at = input.start_at + issues.len();
+    let is_last = issues.is_empty() || next_start_at >= total;
+
+    tracing::info!(
+        target: "mcp",
+        tool = "get_backlog",
+        board_id = input.board_id,
+        returned = issues.len(),
+        total = total,
+        "Backlog page fetched"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "board_id": input.board_id,
+        "issues": issues,
+        "count": issues.len(),
+        "total": total,
+        "start_at": input.start_at,
+        "next_start_at": (!is_last).then_some(next_start_at),
+        "is_last": is_last,
+        "estimation_field": estimation_field
+    })))
+}
+
+pub async fn rank_issues_handler(
+    input: RankIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "rank_issues",
+        issues = ?input.issue_keys,
+        before = ?input.before,
+        after = ?input.after,
+        "Ranking issues"
+    );
+
+    if input.issue_keys.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params("issue_keys must not be empty", None));
+    }
+
+    let anchor = match (&input.before, &input.after) {
+        (Some(before), None) => before.clone(),
+        (None, Some(after)) => after.clone(),
+        _ => {
+            return Err(rmcp::ErrorData::invalid_params(
+                "Provide exactly one of 'before' or 'after'",
+                None,
+            ));
+        }
+    };
+
+    if input.issue_keys.iter().any(|k| k.eq_ignore_ascii_case(&anchor)) {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("{} cannot be ranked relative to itself", anchor),
+            Some(json!({ "anchor": anchor })),
+        ));
+    }
+
+    let rank_field_id = match input.board_id {
+        Some(board_id) => {
+            let cfg = ctx
+                .client
+                .get_board_configuration(board_id, &ctx.auth)
+                .await
+                .mcp_context(
+                    error_ctx!("rank_issues", "get board configuration")
+                        .with_metadata("board_id", board_id.to_string())
+                        .with_suggestions(get_sprint_suggestions)
+                )?;
+            cfg.get("ranking")
+                .and_then(|r| r.get("rankCustomFieldId"))
+                .and_then(|id| id.as_u64())
+        }
+        None => None,
+    };
+
+    // Batches after the first go directly after the previous batch, keeping the requested order
+    let mut ranked = Vec::new();
+    let mut failed = Vec::new();
+    let mut previous_last: Option<String> = None;
+
+    for batch in input.issue_keys.chunks(RANK_BATCH_SIZE) {
+        let (before, after) = match &previous_last {
+            Some(last) => (None, Some(last.as_str())),
+            None => (input.before.as_deref(), input.after.as_deref()),
+        };
+
+        let response = ctx
+            .client
+            .rank_issues(batch, before, after, rank_field_id, &ctx.auth)
+            .await
+            .mcp_context(
+                error_ctx!("rank_issues", "rank issues")
+                    .with_metadata("anchor", anchor.clone())
+                    .with_metadata("issues", batch.join(","))
+            )?;
+
+        let batch_failures: Vec<Value> = response
+            .get("entries")
+            .and_then(|e| e.as_array())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.get("status").and_then(|s| s.as_u64()).unwrap_or(200) >= 400)
+                    .map(|e| {
+                        json!({
+                            "key": e.get("issueKey"),
+                            "status": e.get("status"),
+                            "errors": e.get("errors")
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for key in batch {
+            let key_failed = batch_failures
+                .iter()
+                .any(|f| f.get("key").and_then(|k| k.as_str()) == Some(key.as_str()));
+            if !key_failed {
+                ranked.push(key.clone());
+            }
+        }
+        failed.extend(batch_failures);
+        previous_last = batch.last().cloned();
+    }
+
+    tracing::info!(
+        target: "mcp",
+        tool = "rank_issues",
+        ranked = ranked.len(),
+        failed = failed.len(),
+        "Issues ranked"
+    );
+
+    let position = if input.before.is_some() { "before" } else { "after" };
+    Ok(CallToolResult::structured(json!({
+        "success": failed.is_empty(),
+        "message": format!("Ranked {} issue(s) {} {}", ranked.len(), position, anchor),
+        "ranked": ranked,
+        "failed": failed,
+        "position": position,
+        "anchor": anchor
+    })))
+}
@@ -0,0 +1,318 @@
+use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::suggestions::get_component_suggestions;
+use crate::errors::{describe_error, ResultMcpExt};
+use crate::models::{
+    ComponentAssigneeType, CreateComponentInput, DeleteComponentInput, ListComponentsInput, UpdateComponentInput,
+};
+use crate::utils::edit_distance;
+
+fn component_summary(component: &Value) -> Value {
+    json!({
+        "id": component.get("id"),
+        "name": component.get("name"),
+        "description": component.get("description"),
+        "lead": component.get("lead").and_then(|l| l.get("displayName")),
+        "lead_account_id": component.get("lead").and_then(|l| l.get("accountId")),
+        "assignee_type": component.get("assigneeType"),
+        "issue_count": component.get("issueCount")
+    })
+}
+
+fn component_payload(
+    name: Option<&str>,
+    description: Option<&str>,
+    lead_account_id: Option<&str>,
+    assignee_type: Option<ComponentAssigneeType>,
+) -> Map<String, Value> {
+    let mut payload = Map::new();
+    if let Some(name) = name {
+        payload.insert("name".into(), json!(name));
+    }
+    if let Some(description) = description {
+        payload.insert("description".into(), json!(description));
+    }
+    if let Some(lead) = lead_account_id {
+        payload.insert("leadAccountId".into(), json!(lead));
+    }
+    if let Some(assignee_type) = assignee_type {
+        payload.insert("assigneeType".into(), json!(assignee_type.as_jira()));
+    }
+    payload
+}
+
+pub async fn list_components_handler(
+    input: ListComponentsInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "list_components", project_key = %input.project_key);
+
+    let components = ctx
+        .client
+        .list_components(&input.project_key, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("list_components", "list components")
+                .with_metadata("project_key", input.project_key.clone())
+                .with_suggestions(get_component_suggestions)
+        )?;
+
+    let components: Vec<Value> = components.iter().map(component_summary).collect();
+
+    Ok(CallToolResult::structured(json!({
+        "project_key": input.project_key,
+        "components": components,
+        "count": components.len()
+    })))
+}
+
+pub async fn create_component_handler(
+    input: CreateComponentInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "create_component",
+        project_key = %input.project_key,
+        name = %input.name,
+        "Creating component"
+    );
+
+    if input.name.trim().is_empty() {
+        return Err(rmcp::ErrorData::invalid_params("Component name must not be empty", None));
+    }
+
+    let mut payload = component_payload(
+        Some(input.name.trim()),
+        input.description.as_deref(),
+        input.lead_account_id.as_deref(),
+        input.assignee_type,
+    );
+    payload.insert("project".into(), json!(input.project_key));
+
+    let created = ctx
+        .client
+        .create_component(&Value::Object(payload), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_component", "create component")
+                .with_metadata("project_key", input.project_key.clone())
+                .with_metadata("name", input.name.clone())
+                .with_suggestions(get_component_suggestions)
+        )?;
+
+    tracing::info!(target: "mcp", tool = "create_component", id = ?created.get("id"), "Component created");
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "component": component_summary(&created)
+    })))
+}
+
+pub async fn update_component_handler(
+    input: UpdateComponentInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "update_component", component_id = %input.component_id);
+
+    let payload = component_payload(
+        input.name.as_deref().map(str::trim),
+        input.description.as_deref(),
+        input.lead_account_id.as_deref(),
+        input.assignee_type,
+    );
+    if payload.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Nothing to update: pass name, description, lead_account_id or assignee_type",
+            None,
+        ));
+    }
+    let updated_fields: Vec<String> = payload.keys().cloned().collect();
+
+    let updated = ctx
+        .client
+        .update_component(&input.component_id, &Value::Object(payload), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("update_component", "update component")
+                .with_metadata("component_id", input.component_id.clone())
+                .with_suggestions(get_component_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "component": component_summary(&updated),
+        "updated_fields": updated_fields
+    })))
+}
+
+pub async fn delete_component_handler(
+    input: DeleteComponentInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "delete_component",
+        component_id = %input.component_id,
+        move_issues_to = ?input.move_issues_to
+    );
+
+    let component = ctx
+        .client
+        .get_component(&input.component_id, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("delete_component", "get component")
+                .with_metadata("component_id", input.component_id.clone())
+                .with_suggestions(get_component_suggestions)
+        )?;
+
+    // A reassignment target given by name is looked up in the same project
+    let target = match input.move_issues_to.as_deref().map(str::trim) {
+        Some(t) if !t.is_empty() && !t.chars().all(|c| c.is_ascii_digit()) => {
+            let project_key = component
+                .get("project")
+                .and_then(|p| p.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let components = ctx
+                .client
+                .list_components(&project_key, &ctx.auth)
+                .await
+                .mcp_context(
+                    error_ctx!("delete_component", "list components")
+                        .with_metadata("project_key", project_key.clone())
+                )?;
+            let found = components
+                .iter()
+                .find(|c| c.get("name").and_then(|n| n.as_str()).is_some_and(|n| n.eq_ignore_ascii_case(t)))
+                .and_then(|c| c.get("id").and_then(|id| id.as_str()))
+                .map(|id| id.to_string());
+
+            match found {
+                Some(id) => Some(id),
+                None => {
+                    return Err(rmcp::ErrorData::invalid_params(
+                        format!("No component named '{}' in project {}", t, project_key),
+                        Some(json!({ "available": component_names(&components) })),
+                    ));
+                }
+            }
+        }
+        Some(t) if !t.is_empty() => Some(t.to_string()),
+        _ => None,
+    };
+
+    if target.as_deref() == Some(input.component_id.as_str()) {
+        return Err(rmcp::ErrorData::invalid_params(
+            "move_issues_to must be a different component",
+            None,
+        ));
+    }
+
+    ctx.client
+        .delete_component(&input.component_id, target.as_deref(), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("delete_component", "delete component")
+                .with_metadata("component_id", input.component_id.clone())
+                .with_suggestions(get_component_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "deleted": component_summary(&component),
+        "issues_moved_to": target
+    })))
+}
+
+fn component_names(components: &[Value]) -> Vec<&str> {
+    components
+        .iter()
+        .filter_map(|c| c.get("name").and_then(|n| n.as_str()))
+        .collect()
+}
+
+/// Check component names in create/update fields against the project's components.
+/// Lookup failures are logged and skipped so Jira can still report the problem itself.
+pub(crate) async fn validate_component_names(
+    tool: &'static str,
+    project_key: &str,
+    fields: &Value,
+    ctx: &JiraCtx,
+) -> Result<(), rmcp::ErrorData> {
+    let Some(requested) = fields.get("components").and_then(|c| c.as_array()) else {
+        return Ok(());
+    };
+
+    let names: Vec<&str> = requested
+        .iter()
+        .filter_map(|c| match c {
+            Value::String(name) => Some(name.as_str()),
+            Value::Object(obj) if !obj.contains_key("id") => obj.get("name").and_then(|n| n.as_str()),
+            _ => None,
+        })
+        .collect();
+    if names.is_empty() {
+        return Ok(());
+    }
+
+    let components = match ctx.client.list_components(project_key, &ctx.auth).await {
+        Ok(components) => components,
+        Err(e) => {
+            tracing::warn!(
+                target: "mcp",
+                tool = tool,
+                project_key = %project_key,
+                error = %describe_error(&e),
+                "Could not list components, skipping validation"
+            );
+            return Ok(());
+        }
+    };
+    let known = component_names(&components);
+
+    let unknown: Vec<Value> = names
+        .iter()
+        .filter(|name| !known.contains(name))
+        .map(|name| {
+            let lowered = name.to_lowercase();
+            let mut close: Vec<&str> = known
+                .iter()
+                .copied()
+                .filter(|k| {
+                    let k_lower = k.to_lowercase();
+                    k_lower == lowered || edit_distance(&k_lower, &lowered) <= 2
+                })
+                .collect();
+            close.truncate(3);
+            json!({ "name": name, "did_you_mean": close })
+        })
+        .collect();
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    tracing::warn!(target: "mcp", tool = tool, project_key = %project_key, unknown = ?unknown, "Unknown components");
+
+    Err(rmcp::ErrorData::invalid_params(
+        format!(
+            "Unknown component(s) in project {}: {}",
+            project_key,
+            unknown
+                .iter()
+                .filter_map(|u| u.get("name").and_then(|n| n.as_str()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Some(json!({
+            "unknown": unknown,
+            "available": known,
+            "hint": "Use list_components to see the project's components, or create_component to add one"
+        })),
+    ))
+}
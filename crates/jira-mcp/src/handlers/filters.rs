@@ -0,0 +1,332 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{log_err, ResultMcpExt};
+use crate::errors::suggestions::get_filter_suggestions;
+use crate::handlers::issues::run_search;
+use crate::models::{
+    CreateFilterInput, FilterSharePermissionInput, ListFiltersInput, RunFilterInput,
+    SearchIssuesInput, UpdateFilterInput,
+};
+
+pub async fn list_filters_handler(
+    input: ListFiltersInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    let scope = input.scope.as_deref().unwrap_or("all").to_lowercase();
+    tracing::info!(target: "mcp", tool = "list_filters", scope = %scope, name = ?input.name);
+
+    let include_favourites = matches!(scope.as_str(), "all" | "favourite" | "favorite" | "favourites");
+    let include_owned = matches!(scope.as_str(), "all" | "owned" | "my" | "mine");
+
+    if !include_favourites && !include_owned {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("Unknown scope '{}'. Use 'favourite', 'owned' or 'all'", scope),
+            None,
+        ));
+    }
+
+    let mut filters: Vec<Value> = Vec::new();
+
+    if include_favourites {
+        let favourites = ctx
+            .client
+            .list_favourite_filters(&ctx.auth)
+            .await
+            .mcp_context(error_ctx!("list_filters", "list favourite filters"))?;
+        filters.extend(favourites);
+    }
+
+    if include_owned {
+        let me = ctx
+            .client
+            .get_myself(&ctx.auth)
+            .await
+            .map_err(|e| log_err("list_filters", "jira_error", e.to_string()))?;
+
+        let owned = ctx
+            .client
+            .search_filters(input.name.as_deref(), me.account_id.as_deref(), &ctx.auth)
+            .await
+            .mcp_context(error_ctx!("list_filters", "search owned filters"))?;
+        filters.extend(owned);
+    }
+
+    let name_query = input.name.as_deref().map(|n| n.to_lowercase());
+    let mut seen: HashSet<String> = HashSet::new();
+    let summaries: Vec<Value> = filters
+        .iter()
+        .filter(|f| {
+            let id = f.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string();
+            seen.insert(id)
+        })
+        .filter(|f| match &name_query {
+            Some(q) => f
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(|n| n.to_lowercase().contains(q))
+                .unwrap_or(false),
+            None => true,
+        })
+        .map(summarize_filter)
+        .collect();
+
+    tracing::info!(target: "mcp", tool = "list_filters", count = summaries.len(), "Filters listed successfully");
+
+    Ok(CallToolResult::structured(json!({
+        "filters": summaries,
+        "count": summaries.len(),
+        "scope": scope
+    })))
+}
+
+pub async fn run_filter_handler(
+    input: RunFilterInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "run_filter", filter_id = input.filter_id, limit = input.limit);
+
+    let filter = ctx
+        .client
+        .get_filter(input.filter_id, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("run_filter", "get filter")
+                .with_metadata("filter_id", input.filter_id)
+                .with_suggestions(get_filter_suggestions)
+        )?;
+
+    let jql = filter
+        .get("jql")
+        .and_then(|j| j.as_str())
+        .ok_or_else(|| {
+            rmcp::ErrorData::invalid_request(
+                format!("Filter {} has no JQL or is not visible to you", input.filter_id),
+                Some(json!({ "filter_id": input.filter_id })),
+            )
+        })?
+        .to_string();
+
+    let search = SearchIssuesInput {
+        jql,
+        limit: input.limit,
+        fields: input.fields,
+        next_page_token: input.next_page_token,
+        format: input.format,
+        columns: input.columns,
+    };
+
+    let mut response = run_search(&search, ctx).await?;
+    response["filter"] = summarize_filter(&filter);
+
+    Ok(CallToolResult::structured(response))
+}
+
+pub async fn create_filter_handler(
+    input: CreateFilterInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "create_filter", name = %input.name, jql = %input.jql);
+
+    let mut payload = json!({
+        "name": input.name,
+        "jql": input.jql,
+        "favourite": input.favourite
+    });
+
+    if let Some(description) = &input.description {
+        payload["description"] = Value::String(description.clone());
+    }
+
+    if let Some(perms) = &input.share_permissions {
+        payload["sharePermissions"] = Value::Array(share_permissions_payload(perms, ctx).await?);
+    }
+
+    let created = ctx
+        .client
+        .create_filter(&payload, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_filter", "create filter")
+                .with_metadata("name", input.name.clone())
+                .with_suggestions(get_filter_suggestions)
+        )?;
+
+    tracing::info!(
+        target: "mcp",
+        tool = "create_filter",
+        filter_id = ?created.get("id"),
+        "Filter created successfully"
+    );
+
+    Ok(CallToolResult::structured(summarize_filter(&created)))
+}
+
+pub async fn update_filter_handler(
+    input: UpdateFilterInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "update_filter", filter_id = input.filter_id);
+
+    let existing = ctx
+        .client
+        .get_filter(input.filter_id, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("update_filter", "get filter")
+                .with_metadata("filter_id", input.filter_id)
+                .with_suggestions(get_filter_suggestions)
+        )?;
+
+    // Jira requires the name on every update, so fall back to the current one
+    let name = input
+        .name
+        .clone()
+        .or_else(|| existing.get("name").and_then(|n| n.as_str()).map(|n| n.to_string()))
+        .unwrap_or_default();
+
+    let mut payload = json!({ "name": name });
+
+    if let Some(jql) = &input.jql {
+        payload["jql"] = Value::String(jql.clone());
+    }
+
+    if let Some(description) = &input.description {
+        payload["description"] = Value::String(description.clone());
+    }
+
+    if let Some(favourite) = input.favourite {
+        payload["favourite"] = Value::Bool(favourite);
+    }
+
+    if let Some(perms) = &input.share_permissions {
+        payload["sharePermissions"] = Value::Array(share_permissions_payload(perms, ctx).await?);
+    }
+
+    let updated = ctx
+        .client
+        .update_filter(input.filter_id, &payload, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("update_filter", "update filter")
+                .with_metadata("filter_id", input.filter_id)
+                .with_suggestions(get_filter_suggestions)
+        )?;
+
+    tracing::info!(target: "mcp", tool = "update_filter", filter_id = input.filter_id, "Filter updated successfully");
+
+    Ok(CallToolResult::structured(summarize_filter(&updated)))
+}
+
+async fn share_permissions_payload(
+    perms: &[FilterSharePermissionInput],
+    ctx: &JiraCtx,
+) -> Result<Vec<Value>, rmcp::ErrorData> {
+    let mut out = Vec::new();
+
+    for perm in perms {
+        let share_type = perm.share_type.as_str();
+        let missing = |what: &str| {
+            rmcp::ErrorData::invalid_params(
+                format!("Share permission of type '{}' requires '{}'", share_type, what),
+                None,
+            )
+        };
+
+        let entry = match share_type {
+            "global" | "authenticated" => json!({ "type": share_type }),
+            "group" => {
+                let group = perm.group.as_ref().ok_or_else(|| missing("group"))?;
+                json!({ "type": "group", "group": { "name": group } })
+            }
+            "user" => {
+                let account_id = perm.account_id.as_ref().ok_or_else(|| missing("account_id"))?;
+                json!({ "type": "user", "user": { "accountId": account_id } })
+            }
+            "project" | "projectRole" => {
+                let project = perm.project.as_ref().ok_or_else(|| missing("project"))?;
+                let project_id = resolve_project_id(project, ctx).await?;
+
+                if share_type == "project" {
+                    json!({ "type": "project", "project": { "id": project_id } })
+                } else {
+                    let role_id = perm.role_id.as_ref().ok_or_else(|| missing("role_id"))?;
+                    json!({
+                        "type": "projectRole",
+                        "project": { "id": project_id },
+                        "role": { "id": role_id }
+                    })
+                }
+            }
+            other => {
+                return Err(rmcp::ErrorData::invalid_params(
+                    format!(
+                        "Unknown share permission type '{}'. Use global, authenticated, project, projectRole, group or user",
+                        other
+                    ),
+                    None,
+                ));
+            }
+        };
+
+        out.push(entry);
+    }
+
+    Ok(out)
+}
+
+async fn resolve_project_id(project: &str, ctx: &JiraCtx) -> Result<String, rmcp::ErrorData> {
+    if project.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(project.to_string());
+    }
+
+    let projects = ctx
+        .cache
+        .projects(&ctx.client, &ctx.auth)
+        .await
+        .map_err(|e| log_err("filters", "jira_error", e.to_string()))?;
+
+    projects
+        .iter()
+        .find(|p| p.key.eq_ignore_ascii_case(project))
+        .map(|p| p.id.clone())
+        .ok_or_else(|| {
+            rmcp::ErrorData::invalid_params(format!("Project '{}' not found", project), None)
+        })
+}
+
+fn summarize_filter(filter: &Value) -> Value {
+    let share_permissions: Vec<Value> = filter
+        .get("sharePermissions")
+        .and_then(|s| s.as_array())
+        .map(|arr| {
+            arr.iter()
+                .map(|p| {
+                    json!({
+                        "type": p.get("type").cloned().unwrap_or(Value::Null),
+                        "project": p.get("project").and_then(|pr| pr.get("key")).cloned(),
+                        "role": p.get("role").and_then(|r| r.get("name")).cloned(),
+                        "group": p.get("group").and_then(|g| g.get("name")).cloned(),
+                        "user": p.get("user").and_then(|u| u.get("displayName")).cloned()
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    json!({
+        "id": filter.get("id").cloned().unwrap_or(Value::Null),
+        "name": filter.get("name").cloned().unwrap_or(Value::Null),
+        "jql": filter.get("jql").cloned().unwrap_or(Value::Null),
+        "description": filter.get("description").cloned().unwrap_or(Value::Null),
+        "owner": filter.get("owner").and_then(|o| o.get("displayName")).cloned(),
+        "favourite": filter.get("favourite").cloned().unwrap_or(Value::Null),
+        "view_url": filter.get("viewUrl").cloned().unwrap_or(Value::Null),
+        "share_permissions": share_permissions
+    })
+}
@@ -0,0 +1,430 @@
+use std::collections::{HashMap, HashSet};
+
+use futures::stream::{self, StreamExt};
+use rmcp::model::CallToolResult;
+use serde_json::{json, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{describe_error, ResultMcpExt};
+use crate::handlers::error_utils::get_jql_suggestions;
+use crate::models::{
+    BulkCreateIssuesInput, BulkCreateItemResult, BulkEditIssuesInput, BulkEditItemResult,
+    BulkItemStatus,
+};
+use crate::utils::field_processing::process_jira_fields;
+use crate::utils::{parse_transitions, resolve_transition, TransitionChoice};
+
+pub async fn bulk_create_issues_handler(
+    input: BulkCreateIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "bulk_create_issues",
+        count = input.issues.len(),
+        "Bulk creating issues"
+    );
+
+    if input.issues.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params("issues must not be empty", None));
+    }
+
+    let levels = creation_levels(&input)?;
+    let max_level = levels.iter().copied().max().unwrap_or(0);
+
+    let mut results: Vec<BulkCreateItemResult> = input
+        .issues
+        .iter()
+        .enumerate()
+        .map(|(index, item)| BulkCreateItemResult {
+            index,
+            ref_id: item.ref_id.clone(),
+            status: BulkItemStatus::Skipped,
+            issue_key: None,
+            url: None,
+            error: None,
+        })
+        .collect();
+
+    let mut created_keys: HashMap<String, String> = HashMap::new();
+
+    // Parents must exist before their children can reference them, so create level by level
+    for level in 0..=max_level {
+        let mut indices = Vec::new();
+        let mut payloads = Vec::new();
+
+        for (index, item) in input.issues.iter().enumerate() {
+            if levels[index] != level {
+                continue;
+            }
+
+            let mut fields = process_jira_fields(&item.fields);
+
+            if let Some(parent_ref) = &item.parent_ref {
+                match created_keys.get(parent_ref) {
+                    Some(parent_key) => {
+                        fields.insert("parent".to_string(), json!({ "key": parent_key }));
+                    }
+                    None => {
+                        results[index].error = Some(json!(format!(
+                            "Parent '{}' was not created",
+                            parent_ref
+                        )));
+                        continue;
+                    }
+                }
+            }
+
+            indices.push(index);
+            payloads.push(json!({ "fields": fields }));
+        }
+
+        if payloads.is_empty() {
+            continue;
+        }
+
+        match ctx.client.bulk_create_issues(&payloads, &ctx.auth).await {
+            Ok(outcomes) => {
+                for (index, outcome) in indices.into_iter().zip(outcomes) {
+                    let result = &mut results[index];
+
+                    match outcome.key {
+                        Some(key) => {
+                            result.url = ctx
+                                .client
+                                .base_url()
+                                .join(&format!("/browse/{}", key))
+                                .ok()
+                                .map(|u| u.to_string());
+                            result.status = BulkItemStatus::Created;
+                            result.issue_key = Some(key.clone());
+
+                            if let Some(ref_id) = &input.issues[index].ref_id {
+                                created_keys.insert(ref_id.clone(), key);
+                            }
+                        }
+                        None => {
+                            result.status = BulkItemStatus::Failed;
+                            result.error = Some(json!({
+                                "status_code": outcome.status,
+                                "errors": outcome.errors
+                            }));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::error!(
+                    target: "mcp",
+                    tool = "bulk_create_issues",
+                    error = %e,
+                    level = level,
+                    "Bulk create request failed"
+                );
+
+                let error = match e.downcast_ref::<jira_client::error::JiraError>() {
+                    Some(jira_client::error::JiraError::ApiError { status_code, response }) => json!({
+                        "status_code": status_code,
+                        "errors": response
+                    }),
+                    _ => json!(e.to_string()),
+                };
+
+                for index in indices {
+                    results[index].status = BulkItemStatus::Failed;
+                    results[index].error = Some(error.clone());
+                }
+            }
+        }
+    }
+
+    let count = |status: BulkItemStatus| results.iter().filter(|r| r.status == status).count();
+    let created = count(BulkItemStatus::Created);
+    let failed = count(BulkItemStatus::Failed);
+    let skipped = count(BulkItemStatus::Skipped);
+
+    tracing::info!(
+        target: "mcp",
+        tool = "bulk_create_issues",
+        created = created,
+        failed = failed,
+        skipped = skipped,
+        "Bulk create finished"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "created": created,
+        "failed": failed,
+        "skipped": skipped,
+        "results": results
+    })))
+}
+
+/// Depth of each item in the parent_ref chain; parents must appear earlier in the batch
+fn creation_levels(input: &BulkCreateIssuesInput) -> Result<Vec<usize>, rmcp::ErrorData> {
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    let mut levels = Vec::with_capacity(input.issues.len());
+
+    for (index, item) in input.issues.iter().enumerate() {
+        let level = match item.parent_ref.as_deref() {
+            Some(parent_ref) => {
+                let parent = positions.get(parent_ref).ok_or_else(|| {
+                    rmcp::ErrorData::invalid_params(
+                        format!(
+                            "Item {} references parent_ref '{}' which is not the ref_id of an earlier item",
+                            index, parent_ref
+                        ),
+                        None,
+                    )
+                })?;
+                levels[*parent] + 1
+            }
+            None => 0,
+        };
+
+        if let Some(ref_id) = item.ref_id.as_deref() {
+            if positions.insert(ref_id, index).is_some() {
+                return Err(rmcp::ErrorData::invalid_params(
+                    format!("Duplicate ref_id '{}'", ref_id),
+                    None,
+                ));
+            }
+        }
+
+        levels.push(level);
+    }
+
+    Ok(levels)
+}
+
+const DEFAULT_BULK_EDIT_CONCURRENCY: usize = 5;
+const MAX_BULK_EDIT_CONCURRENCY: usize = 10;
+
+pub async fn bulk_edit_issues_handler(
+    input: BulkEditIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "bulk_edit_issues",
+        jql = ?input.jql,
+        issue_keys = ?input.issue_keys,
+        dry_run = input.dry_run,
+        "Bulk editing issues"
+    );
+
+    let add_labels = input.add_labels.clone().unwrap_or_default();
+    let remove_labels = input.remove_labels.clone().unwrap_or_default();
+    let fields = input
+        .fields
+        .as_ref()
+        .map(process_jira_fields)
+        .unwrap_or_default();
+
+    if fields.is_empty()
+        && add_labels.is_empty()
+        && remove_labels.is_empty()
+        && input.transition.is_none()
+        && input.assignee.is_none()
+    {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Nothing to change: set fields, add_labels, remove_labels, transition or assignee",
+            None,
+        ));
+    }
+
+    let (keys, truncated) = resolve_edit_targets(&input, ctx).await?;
+
+    let mut planned = Vec::new();
+    if !fields.is_empty() {
+        planned.push(format!("set fields: {}", fields.keys().cloned().collect::<Vec<_>>().join(", ")));
+    }
+    if !add_labels.is_empty() {
+        planned.push(format!("add labels: {}", add_labels.join(", ")));
+    }
+    if !remove_labels.is_empty() {
+        planned.push(format!("remove labels: {}", remove_labels.join(", ")));
+    }
+    if let Some(assignee) = &input.assignee {
+        planned.push(format!("assign: {}", assignee));
+    }
+    if let Some(transition) = &input.transition {
+        planned.push(format!("transition: {}", transition));
+    }
+
+    if input.dry_run {
+        return Ok(CallToolResult::structured(json!({
+            "dry_run": true,
+            "issue_keys": keys,
+            "count": keys.len(),
+            "truncated": truncated,
+            "planned_changes": planned
+        })));
+    }
+
+    let mut update_ops = serde_json::Map::new();
+    let label_ops: Vec<_> = add_labels
+        .iter()
+        .map(|l| json!({ "add": l }))
+        .chain(remove_labels.iter().map(|l| json!({ "remove": l })))
+        .collect();
+    if !label_ops.is_empty() {
+        update_ops.insert("labels".to_string(), Value::Array(label_ops));
+    }
+
+    let mut edit_payload = serde_json::Map::new();
+    if !fields.is_empty() {
+        edit_payload.insert("fields".to_string(), Value::Object(fields));
+    }
+    if !update_ops.is_empty() {
+        edit_payload.insert("update".to_string(), Value::Object(update_ops));
+    }
+    let edit_payload = (!edit_payload.is_empty()).then_some(Value::Object(edit_payload));
+
+    let assignee = input.assignee.as_deref().map(|a| {
+        if a.eq_ignore_ascii_case("unassigned") || a.eq_ignore_ascii_case("none") {
+            None
+        } else {
+            Some(a)
+        }
+    });
+
+    let concurrency = input
+        .concurrency
+        .unwrap_or(DEFAULT_BULK_EDIT_CONCURRENCY)
+        .clamp(1, MAX_BULK_EDIT_CONCURRENCY);
+
+    let edits: Vec<_> = keys
+        .iter()
+        .map(|key| edit_one(key, edit_payload.as_ref(), assignee, input.transition.as_deref(), ctx))
+        .collect();
+
+    let results: Vec<BulkEditItemResult> = stream::iter(edits)
+        .buffered(concurrency)
+        .collect()
+        .await;
+
+    let updated = results.iter().filter(|r| r.status == BulkItemStatus::Updated).count();
+    let failed = results.len() - updated;
+
+    tracing::info!(
+        target: "mcp",
+        tool = "bulk_edit_issues",
+        updated = updated,
+        failed = failed,
+        "Bulk edit finished"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "dry_run": false,
+        "updated": updated,
+        "failed": failed,
+        "truncated": truncated,
+        "results": results
+    })))
+}
+
+/// Resolve the explicit key list or the JQL result into issue keys
+async fn resolve_edit_targets(
+    input: &BulkEditIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<(Vec<String>, bool), rmcp::ErrorData> {
+    if let Some(keys) = input.issue_keys.as_ref().filter(|k| !k.is_empty()) {
+        let mut seen = HashSet::new();
+        let keys = keys
+            .iter()
+            .map(|k| k.trim().to_uppercase())
+            .filter(|k| seen.insert(k.clone()))
+            .collect();
+        return Ok((keys, false));
+    }
+
+    let Some(jql) = input.jql.as_deref() else {
+        return Err(rmcp::ErrorData::invalid_params("Provide either jql or issue_keys", None));
+    };
+
+    let jql_for_hints = jql.to_string();
+    let page = ctx
+        .client
+        .search_issues(jql, Some("key"), None, input.limit, None, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("bulk_edit_issues", "resolve jql")
+                .with_metadata("jql", jql)
+                .with_suggestions(move |status| get_jql_suggestions(&jql_for_hints, status))
+        )?;
+
+    let keys = page
+        .issues
+        .iter()
+        .filter_map(|i| i.get("key").and_then(|k| k.as_str()).map(|k| k.to_string()))
+        .collect();
+
+    Ok((keys, !page.is_last))
+}
+
+async fn edit_one(
+    key: &str,
+    edit_payload: Option<&Value>,
+    assignee: Option<Option<&str>>,
+    transition: Option<&str>,
+    ctx: &JiraCtx,
+) -> BulkEditItemResult {
+    let mut actions = Vec::new();
+
+    let outcome: anyhow::Result<()> = async {
+        if let Some(payload) = edit_payload {
+            ctx.client.update_issue(key, payload, &ctx.auth).await?;
+            actions.push("edited".to_string());
+        }
+
+        if let Some(account_id) = assignee {
+            ctx.client.assign_issue(key, account_id, &ctx.auth).await?;
+            actions.push("assigned".to_string());
+        }
+
+        if let Some(wanted) = transition {
+            let transitions = parse_transitions(&ctx.client.get_transitions(key, None, &ctx.auth).await?);
+            let transition_id = match resolve_transition(&transitions, wanted) {
+                TransitionChoice::Confident(t) => t.id.clone(),
+                TransitionChoice::Candidates(candidates) => anyhow::bail!(
+                    "No clear transition for '{}'; candidates: {}",
+                    wanted,
+                    candidates.iter().map(|t| format!("{} (to {})", t.name, t.to)).collect::<Vec<_>>().join(", ")
+                ),
+                TransitionChoice::NoMatch => {
+                    anyhow::bail!("No transition named or leading to '{}' is available", wanted)
+                }
+            };
+            ctx.client
+                .transition_issue(key, &transition_id, None, None, &ctx.auth)
+                .await?;
+            actions.push(format!("transitioned to {}", wanted));
+        }
+
+        Ok(())
+    }
+    .await;
+
+    match outcome {
+        Ok(()) => BulkEditItemResult {
+            issue_key: key.to_string(),
+            status: BulkItemStatus::Updated,
+            actions,
+            error: None,
+        },
+        Err(e) => {
+            let error = describe_error(&e);
+
+            tracing::warn!(target: "mcp", tool = "bulk_edit_issues", issue_key = %key, error = %error, "Bulk edit failed for issue");
+
+            BulkEditItemResult {
+                issue_key: key.to_string(),
+                status: BulkItemStatus::Failed,
+                actions,
+                error: Some(error),
+            }
+        }
+    }
+}
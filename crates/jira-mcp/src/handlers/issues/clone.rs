@@ -0,0 +1,418 @@
+use std::collections::HashSet;
+
+use jira_client::models::Issue;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{describe_error, log_err, ResultMcpExt};
+use crate::models::CloneIssueInput;
+use crate::utils::process_jira_fields;
+
+const DEFAULT_SUMMARY_PREFIX: &str = "CLONE - ";
+const DEFAULT_CLONE_LINK_TYPE: &str = "Cloners";
+
+/// Fields that are copied separately (or never) rather than through the create payload
+const NON_COPYABLE_FIELDS: &[&str] = &[
+    "issuelinks",
+    "attachment",
+    "comment",
+    "worklog",
+    "subtasks",
+    "status",
+    "resolution",
+    "resolutiondate",
+    "votes",
+    "watches",
+    "created",
+    "updated",
+    "creator",
+];
+
+/// Custom field types whose values cannot be written back as read
+const NON_COPYABLE_CUSTOM_TYPES: &[&str] = &[":gh-sprint", ":gh-lexo-rank"];
+
+struct CloneOptions<'a> {
+    overrides: Map<String, Value>,
+    summary_prefix: &'a str,
+    include_labels: bool,
+    include_attachments: bool,
+}
+
+struct CloneOutcome {
+    key: String,
+    url: String,
+    copied_fields: Vec<String>,
+    dropped_fields: Vec<String>,
+    attachments_copied: usize,
+    warnings: Vec<String>,
+}
+
+pub async fn clone_issue_handler(
+    input: CloneIssueInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "clone_issue",
+        issue_key = %input.issue_key,
+        include_subtasks = input.include_subtasks,
+        include_links = input.include_links,
+        include_attachments = input.include_attachments,
+        "Cloning issue"
+    );
+
+    let source_key = input.issue_key.trim().to_uppercase();
+    let options = CloneOptions {
+        overrides: input
+            .overrides
+            .as_ref()
+            .map(process_jira_fields)
+            .unwrap_or_default(),
+        summary_prefix: input.summary_prefix.as_deref().unwrap_or(DEFAULT_SUMMARY_PREFIX),
+        include_labels: input.include_labels,
+        include_attachments: input.include_attachments,
+    };
+
+    let source = ctx
+        .client
+        .get_issue_raw(&source_key, "*all", &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("clone_issue", "get issue").with_metadata("issue_key", source_key.clone()))?;
+
+    let clone = clone_one(&source, &options, None, ctx).await?;
+    let mut warnings = clone.warnings.clone();
+
+    let link_type = input.link_type.as_deref().unwrap_or(DEFAULT_CLONE_LINK_TYPE);
+    let mut linked_to_original = false;
+
+    if !link_type.is_empty() {
+        match ctx.client.link_issues(&clone.key, &source_key, link_type, &ctx.auth).await {
+            Ok(()) => linked_to_original = true,
+            Err(e) => warnings.push(format!(
+                "Could not link {} to {} with '{}': {}",
+                clone.key,
+                source_key,
+                link_type,
+                describe_error(&e)
+            )),
+        }
+    }
+
+    let mut subtasks = Vec::new();
+    if input.include_subtasks {
+        for subtask_key in subtask_keys(&source) {
+            let result = match ctx.client.get_issue_raw(&subtask_key, "*all", &ctx.auth).await {
+                Ok(subtask) => clone_one(&subtask, &options, Some(&clone.key), ctx)
+                    .await
+                    .map_err(|e| {
+                        e.data
+                            .as_ref()
+                            .and_then(|d| d.get("message"))
+                            .and_then(|m| m.as_str())
+                            .map(|m| m.to_string())
+                            .unwrap_or_else(|| e.message.to_string())
+                    }),
+                Err(e) => Err(describe_error(&e)),
+            };
+
+            subtasks.push(match result {
+                Ok(created) => json!({
+                    "original_key": subtask_key,
+                    "issue_key": created.key,
+                    "url": created.url,
+                    "dropped_fields": created.dropped_fields
+                }),
+                Err(error) => json!({
+                    "original_key": subtask_key,
+                    "error": error
+                }),
+            });
+        }
+    }
+
+    let mut links_created = 0;
+    if input.include_links {
+        for (inward, outward, link_name) in link_specs(&source, &clone.key) {
+            match ctx.client.link_issues(&inward, &outward, &link_name, &ctx.auth).await {
+                Ok(()) => links_created += 1,
+                Err(e) => warnings.push(format!(
+                    "Could not copy '{}' link between {} and {}: {}",
+                    link_name,
+                    inward,
+                    outward,
+                    describe_error(&e)
+                )),
+            }
+        }
+    }
+
+    tracing::info!(
+        target: "mcp",
+        tool = "clone_issue",
+        original_key = %source_key,
+        issue_key = %clone.key,
+        "Issue cloned successfully"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "original_key": source_key,
+        "issue_key": clone.key,
+        "url": clone.url,
+        "copied_fields": clone.copied_fields,
+        "dropped_fields": clone.dropped_fields,
+        "linked_to_original": linked_to_original,
+        "link_type": (!link_type.is_empty()).then_some(link_type),
+        "subtasks": subtasks,
+        "links_copied": links_created,
+        "attachments_copied": clone.attachments_copied,
+        "warnings": warnings
+    })))
+}
+
+/// Create a copy of one issue, optionally under a different parent.
+///
+/// Values are taken from the raw issue so rich text (ADF) fields keep their structure.
+async fn clone_one(
+    source: &Issue,
+    options: &CloneOptions<'_>,
+    parent_override: Option<&str>,
+    ctx: &JiraCtx,
+) -> Result<CloneOutcome, rmcp::ErrorData> {
+    let editmeta = ctx
+        .client
+        .get_issue_editmeta(&source.key, &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("clone_issue", "get editmeta").with_metadata("issue_key", source.key.clone()))?;
+
+    let editable = editmeta
+        .get("fields")
+        .and_then(|f| f.as_object())
+        .cloned()
+        .unwrap_or_default();
+
+    let source_fields = source.fields.as_object().cloned().unwrap_or_default();
+    let value_of = |id: &str| source_fields.get(id);
+
+    let mut copied = Map::new();
+
+    for (id, meta) in &editable {
+        if NON_COPYABLE_FIELDS.contains(&id.as_str()) || (id == "labels" && !options.include_labels) {
+            continue;
+        }
+
+        let custom_type = meta
+            .get("schema")
+            .and_then(|s| s.get("custom"))
+            .and_then(|c| c.as_str())
+            .unwrap_or("");
+
+        if NON_COPYABLE_CUSTOM_TYPES.iter().any(|t| custom_type.ends_with(t)) {
+            continue;
+        }
+
+        let Some(value) = value_of(id).filter(|v| !v.is_null()) else {
+            continue;
+        };
+
+        let settable = match id.as_str() {
+            "timetracking" => match value.get("originalEstimate") {
+                Some(estimate) => json!({ "originalEstimate": estimate }),
+                None => continue,
+            },
+            _ => settable_value(value),
+        };
+
+        copied.insert(id.clone(), settable);
+    }
+
+    if let Some(project_key) = value_of("project").and_then(|p| p.get("key")) {
+        copied.insert("project".into(), json!({ "key": project_key }));
+    }
+
+    if let Some(issue_type_id) = value_of("issuetype").and_then(|t| t.get("id")) {
+        copied.insert("issuetype".into(), json!({ "id": issue_type_id }));
+    }
+
+    match parent_override {
+        Some(parent) => {
+            copied.insert("parent".into(), json!({ "key": parent }));
+        }
+        None => {
+            if let Some(parent) = value_of("parent").and_then(|p| p.get("key")) {
+                copied.insert("parent".into(), json!({ "key": parent }));
+            }
+        }
+    }
+
+    let summary = value_of("summary").and_then(|s| s.as_str()).unwrap_or_default();
+    copied.insert("summary".into(), json!(format!("{}{}", options.summary_prefix, summary)));
+
+    let mut fields = process_jira_fields(&Value::Object(copied));
+    let copied_fields: Vec<String> = fields.keys().cloned().collect();
+    fields.extend(options.overrides.clone());
+
+    let protected: HashSet<&str> = ["project", "issuetype", "summary", "parent"]
+        .into_iter()
+        .chain(options.overrides.keys().map(|k| k.as_str()))
+        .collect();
+
+    let mut dropped_fields = Vec::new();
+
+    // Fields editable on the source may be missing from the create screen; drop those once and retry
+    let (key, url) = match ctx.client.create_issue(&json!({ "fields": fields }), &ctx.auth).await {
+        Ok(created) => created,
+        Err(e) => {
+            let rejected: Vec<String> = match e.downcast_ref::<jira_client::error::JiraError>() {
+                Some(jira_client::error::JiraError::ApiError { status_code: 400, response }) => response
+                    .get("errors")
+                    .and_then(|errs| errs.as_object())
+                    .map(|errs| {
+                        errs.keys()
+                            .filter(|k| fields.contains_key(*k) && !protected.contains(k.as_str()))
+                            .cloned()
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            };
+
+            if rejected.is_empty() {
+                return Err(log_err("clone_issue", "create_failed", describe_error(&e)));
+            }
+
+            for field in &rejected {
+                fields.remove(field);
+            }
+            dropped_fields = rejected;
+
+            ctx.client
+                .create_issue(&json!({ "fields": fields }), &ctx.auth)
+                .await
+                .map_err(|e| log_err("clone_issue", "create_failed", describe_error(&e)))?
+        }
+    };
+
+    let mut warnings = Vec::new();
+    let mut attachments_copied = 0;
+
+    if options.include_attachments {
+        match copy_attachments(&source.key, &key, ctx).await {
+            Ok((copied, failures)) => {
+                attachments_copied = copied;
+                warnings.extend(failures);
+            }
+            Err(e) => warnings.push(format!("Could not list attachments of {}: {}", source.key, describe_error(&e))),
+        }
+    }
+
+    Ok(CloneOutcome {
+        key,
+        url,
+        copied_fields: copied_fields
+            .into_iter()
+            .filter(|f| !dropped_fields.contains(f))
+            .collect(),
+        dropped_fields,
+        attachments_copied,
+        warnings,
+    })
+}
+
+/// Reduce a read value to the reference form Jira accepts on create; ADF documents pass through unchanged
+fn settable_value(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) if obj.get("type").and_then(|t| t.as_str()) == Some("doc") => value.clone(),
+        Value::Object(obj) => {
+            if let Some(account_id) = obj.get("accountId") {
+                return json!({ "accountId": account_id });
+            }
+
+            if let Some(id) = obj.get("id") {
+                let mut out = json!({ "id": id });
+                if let Some(child) = obj.get("child") {
+                    out["child"] = settable_value(child);
+                }
+                return out;
+            }
+
+            if let Some(key) = obj.get("key") {
+                return json!({ "key": key });
+            }
+
+            Value::Object(obj.clone())
+        }
+        Value::Array(arr) => Value::Array(arr.iter().map(settable_value).collect()),
+        other => other.clone(),
+    }
+}
+
+fn subtask_keys(source: &Issue) -> Vec<String> {
+    source
+        .fields
+        .get("subtasks")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|s| s.get("key").and_then(|k| k.as_str()).map(|k| k.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// (inward, outward, link type) for each link of the source, re-pointed at the clone
+fn link_specs(source: &Issue, clone_key: &str) -> Vec<(String, String, String)> {
+    let Some(links) = source
+        .fields
+        .get("issuelinks")
+        .and_then(|v| v.as_array())
+    else {
+        return Vec::new();
+    };
+
+    links
+        .iter()
+        .filter_map(|link| {
+            let name = link.get("type")?.get("name")?.as_str()?.to_string();
+            let key_of = |side: &str| {
+                link.get(side)
+                    .and_then(|i| i.get("key"))
+                    .and_then(|k| k.as_str())
+                    .map(|k| k.to_string())
+            };
+
+            match (key_of("outwardIssue"), key_of("inwardIssue")) {
+                (Some(outward), _) => Some((clone_key.to_string(), outward, name)),
+                (None, Some(inward)) => Some((inward, clone_key.to_string(), name)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Download each attachment of `from` and upload it to `to`; returns the copy count and failures
+async fn copy_attachments(from: &str, to: &str, ctx: &JiraCtx) -> anyhow::Result<(usize, Vec<String>)> {
+    let attachments = ctx.client.list_attachments(from, &ctx.auth).await?;
+    let mut copied = 0;
+    let mut failures = Vec::new();
+
+    for attachment in attachments {
+        let id = attachment.get("id").and_then(|i| i.as_str()).unwrap_or_default();
+        let filename = attachment.get("filename").and_then(|f| f.as_str()).unwrap_or(id);
+        let mime_type = attachment.get("mimeType").and_then(|m| m.as_str());
+
+        let result = async {
+            let content = ctx.client.download_attachment(id, &ctx.auth).await?;
+            ctx.client.add_attachment(to, filename, mime_type, content, &ctx.auth).await
+        }
+        .await;
+
+        match result {
+            Ok(_) => copied += 1,
+            Err(e) => failures.push(format!("Could not copy attachment '{}': {}", filename, describe_error(&e))),
+        }
+    }
+
+    Ok((copied, failures))
+}
@@ -6,7 +6,7 @@ use crate::errors::suggestions::get_comment_suggestions;
 use crate::models::{AddCommentInput, GetCommentsInput, UpdateCommentInput, DeleteCommentInput};
 use crate::error_ctx;
 use crate::handlers::error_utils::extract_error_message;
-use jira_client::utils::adf_collect_text;
+use crate::utils::adf_to_plain_text;
 
 pub async fn add_comment_handler(
     input: AddCommentInput,
@@ -64,12 +64,6 @@ pub async fn add_comment_handler(
     ))
 }
 
-fn adf_to_plain_text(adf: &serde_json::Value) -> String {
-    let mut result = String::new();
-    adf_collect_text(adf, &mut result);
-    result.trim().to_string()
-}
-
 pub async fn get_comments_handler(
     input: GetCommentsInput,
     ctx: &JiraCtx,
@@ -136,7 +130,7 @@ pub async fn get_comments_handler(
                         .unwrap_or("Unknown");
                     let body = comment
                         .get("body")
-                        .map(|b| adf_to_plain_text(b))
+                        .map(adf_to_plain_text)
                         .unwrap_or_default();
                     let created = comment.get("created").and_then(|c| c.as_str()).unwrap_or("");
                     let updated = comment.get("updated").and_then(|u| u.as_str()).unwrap_or("");
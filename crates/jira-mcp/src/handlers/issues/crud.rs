@@ -5,8 +5,11 @@ use rmcp::model::CallToolResult;
 use crate::context::JiraCtx;
 use crate::errors::log_err;
 use crate::handlers::error_utils::{extract_error_message, get_jql_suggestions, get_create_suggestions, get_update_suggestions};
-use crate::models::{SearchIssuesInput, GetIssueInput, CreateIssueInput, CreateIssueResult, UpdateIssueInput, UpdateIssueResult};
+use crate::models::{default_similar_limit, default_similarity_threshold, FindSimilarIssuesInput, SearchIssuesInput, SearchOutputFormat, GetIssueInput, IssueVerbosity, CreateIssueInput, CreateIssueResult, UpdateIssueInput, UpdateIssueResult};
 use crate::services::CoreService;
+use super::similar::{description_text, find_similar_issues};
+use crate::handlers::components::validate_component_names;
+use crate::utils::{column_headers, project_issue, render_csv, render_markdown_table, resolve_columns, COMPACT_BASE_FIELDS};
 
 pub async fn create_issue_handler(
     input: CreateIssueInput,
@@ -15,9 +18,66 @@ pub async fn create_issue_handler(
     tracing::info!(
         target: "mcp",
         tool = "create_issue",
+        check_duplicates = input.check_duplicates,
         "Creating Jira issue"
     );
 
+    let mut warnings = Vec::new();
+    if input.check_duplicates {
+        let project = input.fields.get("project")
+            .and_then(|p| p.get("key"))
+            .and_then(|k| k.as_str());
+        let summary = input.fields.get("summary").and_then(|s| s.as_str());
+
+        match (project, summary) {
+            (Some(project), Some(summary)) => {
+                let query = FindSimilarIssuesInput {
+                    project: project.to_string(),
+                    summary: summary.to_string(),
+                    description: Some(description_text(input.fields.get("description"))),
+                    limit: default_similar_limit(),
+                    min_score: default_similarity_threshold(),
+                    include_resolved: true,
+                    issue_type: None,
+                };
+                // A failed check must not block creating the issue
+                let duplicates = match find_similar_issues(&query, ctx).await {
+                    Ok(duplicates) => duplicates,
+                    Err(e) => {
+                        tracing::warn!(
+                            target: "mcp",
+                            tool = "create_issue",
+                            error = %e.message,
+                            "Duplicate check failed, creating issue anyway"
+                        );
+                        warnings.push(format!("Duplicate check failed: {}", e.message));
+                        Vec::new()
+                    }
+                };
+
+                if !duplicates.is_empty() {
+                    tracing::info!(
+                        target: "mcp",
+                        tool = "create_issue",
+                        duplicates = duplicates.len(),
+                        "Possible duplicates found, issue not created"
+                    );
+
+                    return Ok(CallToolResult::structured(serde_json::json!({
+                        "result": "possible_duplicates",
+                        "duplicates": duplicates,
+                        "hint": "Link to an existing issue with link_issues, or call create_issue again without check_duplicates to create it anyway"
+                    })));
+                }
+            }
+            _ => warnings.push("Duplicate check skipped: needs fields.project.key and fields.summary".to_string()),
+        }
+    }
+
+    if let Some(project) = input.fields.get("project").and_then(|p| p.get("key")).and_then(|k| k.as_str()) {
+        validate_component_names("create_issue", project, &input.fields, ctx).await?;
+    }
+
     let svc = CoreService::new(ctx);
 
     let res = svc
@@ -68,7 +128,8 @@ pub async fn create_issue_handler(
             )
         })?;
 
-    let CreateIssueResult::Created(payload) = res;
+    let CreateIssueResult::Created(mut payload) = res;
+    payload.warnings.extend(warnings);
     tracing::info!(
         target: "mcp",
         tool = "create_issue",
@@ -93,6 +154,10 @@ pub async fn update_issue_handler(
         "Updating Jira issue"
     );
 
+    if let Some((project, _)) = input.issue_key.rsplit_once('-') {
+        validate_component_names("update_issue", project, &input.fields, ctx).await?;
+    }
+
     let svc = CoreService::new(ctx);
 
     let res = svc
@@ -157,20 +222,55 @@ pub async fn search_issues_handler(
     input: SearchIssuesInput,
     ctx: &JiraCtx,
 ) -> Result<CallToolResult, rmcp::ErrorData> {
+    let response = run_search(&input, ctx).await?;
+    Ok(CallToolResult::structured(response))
+}
+
+/// Run a search and shape the response according to the requested output format
+pub(crate) async fn run_search(
+    input: &SearchIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<serde_json::Value, rmcp::ErrorData> {
     tracing::info!(
         target: "mcp",
         tool = "search_issues",
         jql = %input.jql,
         limit = input.limit,
-        start_at = ?input.start_at,
+        next_page_token = ?input.next_page_token,
         fields = %input.fields,
+        format = ?input.format,
         "Searching issues"
     );
 
-    let results = ctx
-        .client
-        .search_issues(&input.jql, Some(&input.fields), input.limit, input.start_at, &ctx.auth)
-        .await
+    let requested_columns = input.columns.clone().unwrap_or_default();
+    let (fields_param, expand) = if input.format == SearchOutputFormat::Raw {
+        (input.fields.clone(), None)
+    } else if requested_columns.is_empty() {
+        (COMPACT_BASE_FIELDS.join(","), Some("names"))
+    } else {
+        (format!("{},*navigable", COMPACT_BASE_FIELDS.join(",")), Some("names"))
+    };
+
+    let (search_result, count_result) = tokio::join!(
+        ctx.client.search_issues(
+            &input.jql,
+            Some(&fields_param),
+            expand,
+            input.limit,
+            input.next_page_token.as_deref(),
+            &ctx.auth,
+        ),
+        async {
+            // The total only matters on the first page, so continuation pages skip the extra request
+            if input.next_page_token.is_none() {
+                Some(ctx.client.approximate_issue_count(&input.jql, &ctx.auth).await)
+            } else {
+                None
+            }
+        },
+    );
+
+    let page = search_result
         .map_err(|e| {
             tracing::error!(
                 target: "mcp",
@@ -211,26 +311,95 @@ pub async fn search_issues_handler(
             )
         })?;
 
+    let approximate_count = count_result.and_then(|count| {
+        count
+            .map_err(|e| {
+                tracing::warn!(
+                    target: "mcp",
+                    tool = "search_issues",
+                    error = %e,
+                    "Failed to get approximate issue count"
+                );
+            })
+            .ok()
+    });
+
     tracing::info!(
         target: "mcp",
         tool = "search_issues",
-        count = results.len(),
+        count = page.issues.len(),
+        is_last = page.is_last,
+        approximate_count = ?approximate_count,
         "Search completed"
     );
 
-    Ok(CallToolResult::structured(
-        serde_json::json!({ "results": results }),
-    ))
+    let mut response = serde_json::json!({
+        "count": page.issues.len(),
+        "is_last": page.is_last,
+        "next_page_token": page.next_page_token,
+        "approximate_count": approximate_count
+    });
+
+    if input.format == SearchOutputFormat::Raw {
+        response["results"] = serde_json::Value::Array(page.issues);
+        return Ok(response);
+    }
+
+    let (columns, unresolved) = resolve_columns(&requested_columns, &page.names);
+    let headers = column_headers(&columns);
+    let rows: Vec<serde_json::Map<String, serde_json::Value>> = page
+        .issues
+        .iter()
+        .map(|issue| project_issue(issue, &columns))
+        .collect();
+
+    match input.format {
+        SearchOutputFormat::Markdown => {
+            response["table"] = serde_json::Value::String(render_markdown_table(&headers, &rows));
+        }
+        SearchOutputFormat::Csv => {
+            response["table"] = serde_json::Value::String(render_csv(&headers, &rows));
+        }
+        _ => {
+            response["columns"] = serde_json::json!(headers);
+            response["rows"] = serde_json::json!(rows);
+        }
+    }
+
+    if !unresolved.is_empty() {
+        response["unresolved_columns"] = serde_json::json!(unresolved);
+    }
+
+    Ok(response)
 }
 
+/// Fields fetched for `verbosity: minimal`
+const MINIMAL_ISSUE_FIELDS: &str = "summary,status,issuetype,assignee,priority,parent,labels,updated";
+
 pub async fn get_issue_handler(
     input: GetIssueInput,
     ctx: &JiraCtx,
 ) -> Result<CallToolResult, rmcp::ErrorData> {
-    tracing::info!(target: "mcp", tool = "get_issue", key = %input.key, board_id = %input.board_id);
+    tracing::info!(
+        target: "mcp",
+        tool = "get_issue",
+        key = %input.key,
+        board_id = ?input.board_id,
+        verbosity = ?input.verbosity,
+        fields = ?input.fields,
+        expand = ?input.expand
+    );
+
+    // Explicit fields may be display names, so they are matched locally against the full set
+    let jira_fields = match (&input.fields, input.verbosity) {
+        (None, IssueVerbosity::Minimal) => MINIMAL_ISSUE_FIELDS,
+        _ => "*all",
+    };
+    let expand: Vec<String> = input.expand.iter().map(|e| e.as_str().to_string()).collect();
+
     let mut detail = ctx
         .client
-        .get_issue_detail(&input.key, &ctx.auth)
+        .get_issue_detail_with(&input.key, jira_fields, &expand, &ctx.auth)
         .await
         .map_err(|e| {
             tracing::error!(
@@ -238,7 +407,7 @@ pub async fn get_issue_handler(
                 tool = "get_issue",
                 error = %e,
                 issue_key = %input.key,
-                board_id = %input.board_id,
+                board_id = ?input.board_id,
                 "Failed to get issue"
             );
 
@@ -260,20 +429,109 @@ pub async fn get_issue_handler(
                 None
             )
         })?;
-    let keys = crate::board_utils::compute_board_field_keys(ctx, &detail, input.board_id)
-        .await
-        .map_err(|e| {
-            tracing::error!(
+
+    let mut board_keys: Option<HashSet<String>> = None;
+
+    if let Some(wanted) = &input.fields {
+        retain_issue_fields(&mut detail, |id, name| {
+            wanted.iter().any(|w| w.eq_ignore_ascii_case(id) || w.eq_ignore_ascii_case(name))
+        });
+    } else if input.verbosity == IssueVerbosity::Standard {
+        let board_id = match input.board_id {
+            Some(id) => Some(id),
+            None => {
+                let project_key = detail
+                    .fields
+                    .get("project")
+                    .and_then(|p| p.get("key"))
+                    .and_then(|k| k.as_str())
+                    .map(|k| k.to_string());
+                match project_key {
+                    Some(project_key) => crate::board_utils::resolve_project_board(ctx, &project_key).await,
+                    None => None,
+                }
+            }
+        };
+        if board_id.is_none() {
+            tracing::info!(
                 target: "mcp",
                 tool = "get_issue",
-                error = %e,
                 issue_key = %input.key,
-                board_id = %input.board_id,
-                "Failed to compute board field keys"
+                "No board found, filtering fields by editmeta only"
             );
-            log_err("get_issue", "board_utils_error", e.to_string())
-        })?;
-    filter_issue_fields_for_board(&mut detail, keys);
+        }
+
+        let keys = crate::board_utils::compute_board_field_keys(ctx, &detail, board_id, input.board_id.is_none())
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    target: "mcp",
+                    tool = "get_issue",
+                    error = %e,
+                    issue_key = %input.key,
+                    board_id = ?board_id,
+                    "Failed to compute board field keys"
+                );
+                log_err("get_issue", "board_utils_error", e.to_string())
+            })?;
+        filter_issue_fields_for_board(&mut detail, keys.clone());
+        board_keys = Some(keys);
+    }
+
+    if !input.exclude_fields.is_empty() {
+        retain_issue_fields(&mut detail, |id, name| {
+            !input
+                .exclude_fields
+                .iter()
+                .any(|x| x.eq_ignore_ascii_case(id) || x.eq_ignore_ascii_case(name))
+        });
+    }
+
+    // Rendered values follow the caller's selection rather than `detail.fields`, which has no
+    // rich-text custom fields left: those are the ones where the HTML matters most
+    if let Some(rendered) = detail
+        .expanded
+        .as_mut()
+        .and_then(|e| e.get_mut("renderedFields"))
+        .and_then(|r| r.as_object_mut())
+    {
+        let needs_names = input.fields.is_some() || !input.exclude_fields.is_empty();
+        let field_defs = if needs_names {
+            ctx.cache.fields(&ctx.client, &ctx.auth).await.unwrap_or_else(|e| {
+                tracing::warn!(
+                    target: "mcp",
+                    tool = "get_issue",
+                    error = %e,
+                    "Could not load field names, matching rendered fields by id only"
+                );
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
+        let name_of = |id: &str| {
+            field_defs
+                .iter()
+                .find(|f| f.id == id)
+                .map(|f| f.name.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        let matches = |list: &[String], id: &str, name: &str| {
+            list.iter().any(|w| w.eq_ignore_ascii_case(id) || w.eq_ignore_ascii_case(name))
+        };
+
+        rendered.retain(|id, value| {
+            let name = name_of(id);
+            let selected = match (&input.fields, input.verbosity, &board_keys) {
+                (Some(wanted), _, _) => matches(wanted, id, &name),
+                (None, IssueVerbosity::Minimal, _) => MINIMAL_ISSUE_FIELDS.split(',').any(|f| f == id),
+                (None, _, Some(keys)) => keys.contains(id),
+                (None, _, None) => true,
+            };
+            !value.is_null() && selected && !matches(&input.exclude_fields, id, &name)
+        });
+    }
+
     Ok(CallToolResult::structured(
         serde_json::to_value(detail).unwrap_or(serde_json::json!({})),
     ))
@@ -297,3 +555,16 @@ fn filter_issue_fields_for_board(
         }
     }
 }
+
+/// Keep the mapped fields for which `keep(id, display name)` holds
+fn retain_issue_fields(
+    detail: &mut jira_client::models::IssueDetail,
+    keep: impl Fn(&str, &str) -> bool,
+) {
+    if let Some(obj) = detail.fields.as_object_mut() {
+        obj.retain(|id, entry| {
+            let name = entry.get("name").and_then(|n| n.as_str()).unwrap_or(id);
+            keep(id, name)
+        });
+    }
+}
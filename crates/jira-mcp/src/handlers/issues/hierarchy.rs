@@ -0,0 +1,730 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use futures::stream::{self, StreamExt};
+use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{describe_error, log_err, ResultMcpExt};
+use crate::models::{
+    BulkEditItemResult, BulkItemStatus, CreateSubtaskInput, GetIssueTreeInput, MoveToEpicInput,
+    SetParentInput,
+};
+use crate::utils::{
+    estimate_value, field_clause_name, find_field, process_jira_fields, quote_jql_list, quote_jql_value,
+    status_category_name, story_point_field_ids,
+};
+
+/// Upper bound on issues loaded into one tree
+const MAX_TREE_ISSUES: usize = 500;
+
+/// Keys per `parent in (...)` query
+const PARENT_QUERY_CHUNK: usize = 50;
+
+struct TreeNode {
+    issue: Value,
+    depth: usize,
+    children: Vec<String>,
+}
+
+#[derive(Default)]
+struct Rollup {
+    issues: usize,
+    by_status_category: BTreeMap<String, usize>,
+    story_points: f64,
+    done_story_points: f64,
+}
+
+impl Rollup {
+    fn add(&mut self, category: &str, points: Option<f64>) {
+        self.issues += 1;
+        *self.by_status_category.entry(category.to_string()).or_default() += 1;
+
+        if let Some(points) = points {
+            self.story_points += points;
+
+            if category == "Done" {
+                self.done_story_points += points;
+            }
+        }
+    }
+
+    fn merge(&mut self, other: &Rollup) {
+        self.issues += other.issues;
+
+        for (category, count) in &other.by_status_category {
+            *self.by_status_category.entry(category.clone()).or_default() += count;
+        }
+
+        self.story_points += other.story_points;
+        self.done_story_points += other.done_story_points;
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "issues": self.issues,
+            "by_status_category": self.by_status_category,
+            "story_points": self.story_points,
+            "done_story_points": self.done_story_points
+        })
+    }
+}
+
+pub async fn get_issue_tree_handler(
+    input: GetIssueTreeInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "get_issue_tree",
+        issue_key = %input.issue_key,
+        max_depth = input.max_depth,
+        "Building issue tree"
+    );
+
+    let all_fields = ctx
+        .cache
+        .fields(&ctx.client, &ctx.auth)
+        .await
+        .map_err(|e| log_err("get_issue_tree", "jira_error", e.to_string()))?;
+
+    let story_point_fields = match input.story_points_field.as_deref() {
+        Some(name) => match find_field(&all_fields, name) {
+            Some(field) => vec![field.id.clone()],
+            None => {
+                return Err(rmcp::ErrorData::invalid_params(
+                    format!("Story points field '{}' not found", name),
+                    None,
+                ));
+            }
+        },
+        None => story_point_field_ids(&all_fields),
+    };
+
+    let epic_link = find_field(&all_fields, "Epic Link").filter(|f| f.custom);
+
+    let mut requested = vec!["summary", "status", "issuetype", "assignee", "parent"]
+        .into_iter()
+        .map(|f| f.to_string())
+        .collect::<Vec<_>>();
+    requested.extend(story_point_fields.iter().cloned());
+    if let Some(field) = epic_link {
+        requested.push(field.id.clone());
+    }
+    let fields_param = requested.join(",");
+
+    let root_key = input.issue_key.trim().to_uppercase();
+    let root_page = ctx
+        .client
+        .search_issues(
+            &format!("key = {}", quote_jql_value(&root_key)),
+            Some(&fields_param),
+            None,
+            1,
+            None,
+            &ctx.auth,
+        )
+        .await
+        .mcp_context(
+            error_ctx!("get_issue_tree", "get root issue")
+                .with_metadata("issue_key", root_key.clone())
+        )?;
+
+    let Some(root) = root_page.issues.into_iter().next() else {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("Issue {} not found", root_key),
+            None,
+        ));
+    };
+
+    let mut nodes: HashMap<String, TreeNode> = HashMap::new();
+    nodes.insert(
+        root_key.clone(),
+        TreeNode { issue: root, depth: 0, children: Vec::new() },
+    );
+
+    let mut frontier = vec![root_key.clone()];
+    let mut truncated = false;
+    let mut used_epic_link = false;
+
+    for depth in 1..=input.max_depth {
+        if frontier.is_empty() {
+            break;
+        }
+
+        let mut next_frontier = Vec::new();
+
+        for chunk in frontier.chunks(PARENT_QUERY_CHUNK) {
+            let remaining = MAX_TREE_ISSUES.saturating_sub(nodes.len());
+            if remaining == 0 {
+                truncated = true;
+                break;
+            }
+
+            let keys: Vec<String> = chunk.to_vec();
+            let mut children = fetch_children(
+                &format!("parent in ({})", keys.join(", ")),
+                &fields_param,
+                remaining,
+                ctx,
+            )
+            .await?;
+
+            // Company-managed projects that predate the parent field link stories to epics via Epic Link
+            if let Some(field) = epic_link {
+                let with_children: HashSet<String> = children
+                    .iter()
+                    .filter_map(|c| parent_key(c, None))
+                    .collect();
+
+                let childless_epics: Vec<String> = keys
+                    .iter()
+                    .filter(|k| !with_children.contains(*k))
+                    .filter(|k| is_epic(&nodes[*k].issue))
+                    .cloned()
+                    .collect();
+
+                if !childless_epics.is_empty() {
+                    let jql = format!(
+                        "{} in ({})",
+                        field_clause_name(field),
+                        childless_epics.join(", ")
+                    );
+
+                    match fetch_children(&jql, &fields_param, remaining, ctx).await {
+                        Ok(legacy) if !legacy.is_empty() => {
+                            used_epic_link = true;
+                            children.extend(legacy);
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            tracing::warn!(
+                                target: "mcp",
+                                tool = "get_issue_tree",
+                                error = ?e,
+                                "Epic Link fallback query failed"
+                            );
+                        }
+                    }
+                }
+            }
+
+            for child in children {
+                let Some(key) = child.get("key").and_then(|k| k.as_str()).map(|k| k.to_string()) else {
+                    continue;
+                };
+
+                if nodes.contains_key(&key) {
+                    continue;
+                }
+
+                let Some(parent) = parent_key(&child, epic_link.map(|f| f.id.as_str())) else {
+                    continue;
+                };
+
+                if nodes.len() >= MAX_TREE_ISSUES {
+                    truncated = true;
+                    break;
+                }
+
+                let Some(parent_node) = nodes.get_mut(&parent) else {
+                    continue;
+                };
+                parent_node.children.push(key.clone());
+
+                nodes.insert(key.clone(), TreeNode { issue: child, depth, children: Vec::new() });
+                next_frontier.push(key);
+            }
+        }
+
+        frontier = next_frontier;
+    }
+
+    let depth_limited = input.max_depth > 0 && !frontier.is_empty();
+
+    let mut levels: BTreeMap<usize, Rollup> = BTreeMap::new();
+    for node in nodes.values() {
+        let fields = node.issue.get("fields").cloned().unwrap_or(Value::Null);
+        levels
+            .entry(node.depth)
+            .or_default()
+            .add(&status_category_name(&fields), estimate_value(&fields, &story_point_fields));
+    }
+
+    let (tree, total) = build_node(&root_key, &nodes, &story_point_fields);
+
+    let levels_json: Vec<Value> = levels
+        .iter()
+        .map(|(depth, rollup)| {
+            let mut level = rollup.to_json();
+            level["depth"] = json!(depth);
+            level
+        })
+        .collect();
+
+    tracing::info!(
+        target: "mcp",
+        tool = "get_issue_tree",
+        issue_key = %root_key,
+        issues = nodes.len(),
+        truncated = truncated,
+        "Issue tree built"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "root": tree,
+        "total": total.to_json(),
+        "levels": levels_json,
+        "story_points_fields": story_point_fields,
+        "used_epic_link_fallback": used_epic_link,
+        "truncated": truncated,
+        "depth_limited": depth_limited
+    })))
+}
+
+async fn fetch_children(
+    jql: &str,
+    fields_param: &str,
+    limit: usize,
+    ctx: &JiraCtx,
+) -> Result<Vec<Value>, rmcp::ErrorData> {
+    let page = ctx
+        .client
+        .search_issues(jql, Some(fields_param), None, limit, None, &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("get_issue_tree", "search children").with_metadata("jql", jql))?;
+
+    Ok(page.issues)
+}
+
+/// Key of the issue's parent, from the parent field or the legacy Epic Link field
+fn parent_key(issue: &Value, epic_link_id: Option<&str>) -> Option<String> {
+    let fields = issue.get("fields")?;
+
+    if let Some(key) = fields
+        .get("parent")
+        .and_then(|p| p.get("key"))
+        .and_then(|k| k.as_str())
+    {
+        return Some(key.to_string());
+    }
+
+    epic_link_id
+        .and_then(|id| fields.get(id))
+        .and_then(|v| v.as_str())
+        .map(|k| k.to_string())
+}
+
+fn is_epic(issue: &Value) -> bool {
+    issue
+        .get("fields")
+        .and_then(|f| f.get("issuetype"))
+        .and_then(|t| t.get("name"))
+        .and_then(|n| n.as_str())
+        .map(|n| n.eq_ignore_ascii_case("Epic"))
+        .unwrap_or(false)
+}
+
+/// Render a node with its children and the rollup of everything below it.
+/// Returns the node JSON and the rollup including the node itself.
+fn build_node(
+    key: &str,
+    nodes: &HashMap<String, TreeNode>,
+    story_point_fields: &[String],
+) -> (Value, Rollup) {
+    let node = &nodes[key];
+    let fields = node.issue.get("fields").cloned().unwrap_or(Value::Null);
+    let category = status_category_name(&fields);
+    let points = estimate_value(&fields, story_point_fields);
+
+    let mut descendants = Rollup::default();
+    let mut children = Vec::new();
+
+    for child_key in &node.children {
+        let (child, child_rollup) = build_node(child_key, nodes, story_point_fields);
+        descendants.merge(&child_rollup);
+        children.push(child);
+    }
+
+    let text = |v: Option<&Value>| v.and_then(|s| s.as_str()).map(|s| s.to_string());
+
+    let mut out = Map::new();
+    out.insert("key".into(), json!(key));
+    out.insert("summary".into(), json!(text(fields.get("summary"))));
+    out.insert("issue_type".into(), json!(text(fields.get("issuetype").and_then(|t| t.get("name")))));
+    out.insert("status".into(), json!(text(fields.get("status").and_then(|s| s.get("name")))));
+    out.insert("status_category".into(), json!(category));
+    out.insert("assignee".into(), json!(text(fields.get("assignee").and_then(|a| a.get("displayName")))));
+    out.insert("story_points".into(), json!(points));
+
+    if !children.is_empty() {
+        out.insert("rollup".into(), descendants.to_json());
+        out.insert("children".into(), Value::Array(children));
+    }
+
+    let mut total = Rollup::default();
+    total.add(&category, points);
+    total.merge(&descendants);
+
+    (Value::Object(out), total)
+}
+
+pub async fn create_subtask_handler(
+    input: CreateSubtaskInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "create_subtask", parent_key = %input.parent_key, "Creating subtask");
+
+    let parent_key = input.parent_key.trim().to_uppercase();
+    let parents = fetch_hierarchy_info(std::slice::from_ref(&parent_key), "create_subtask", ctx).await?;
+    let parent = parents.get(&parent_key).ok_or_else(|| {
+        rmcp::ErrorData::invalid_params(format!("Issue {} not found", parent_key), None)
+    })?;
+
+    if hierarchy_level(parent) < 0 {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("{} is itself a subtask; subtasks cannot have subtasks", parent_key),
+            None,
+        ));
+    }
+
+    let project_key = parent
+        .get("project")
+        .and_then(|p| p.get("key"))
+        .and_then(|k| k.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let issue_types = ctx
+        .client
+        .list_issue_types(Some(&project_key), &ctx.auth)
+        .await
+        .map_err(|e| log_err("create_subtask", "jira_error", e.to_string()))?;
+
+    let subtask_types: Vec<_> = issue_types.iter().filter(|t| t.subtask).collect();
+    let issue_type = match input.issue_type.as_deref() {
+        Some(name) => subtask_types.iter().find(|t| t.name.eq_ignore_ascii_case(name)),
+        None => subtask_types.first(),
+    }
+    .ok_or_else(|| {
+        let available: Vec<&str> = subtask_types.iter().map(|t| t.name.as_str()).collect();
+        rmcp::ErrorData::invalid_params(
+            match input.issue_type.as_deref() {
+                Some(name) => format!(
+                    "'{}' is not a subtask issue type in {}. Available: {}",
+                    name,
+                    project_key,
+                    available.join(", ")
+                ),
+                None => format!("Project {} has no subtask issue type", project_key),
+            },
+            None,
+        )
+    })?;
+
+    let mut fields = input
+        .fields
+        .as_ref()
+        .map(process_jira_fields)
+        .unwrap_or_default();
+    fields.insert("project".into(), json!({ "key": project_key }));
+    fields.insert("parent".into(), json!({ "key": parent_key }));
+    fields.insert("issuetype".into(), json!({ "id": issue_type.id }));
+    fields.insert("summary".into(), json!(input.summary));
+
+    if let Some(description) = &input.description {
+        fields.extend(process_jira_fields(&json!({ "description": description })));
+    }
+
+    let (key, url) = ctx
+        .client
+        .create_issue(&json!({ "fields": fields }), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_subtask", "create issue")
+                .with_metadata("parent_key", parent_key.clone())
+                .with_metadata("issue_type", issue_type.name.clone())
+        )?;
+
+    tracing::info!(target: "mcp", tool = "create_subtask", issue_key = %key, "Subtask created successfully");
+
+    Ok(CallToolResult::structured(json!({
+        "issue_key": key,
+        "url": url,
+        "parent_key": parent_key,
+        "issue_type": issue_type.name
+    })))
+}
+
+pub async fn set_parent_handler(
+    input: SetParentInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "set_parent",
+        issue_key = %input.issue_key,
+        parent_key = ?input.parent_key,
+        "Setting issue parent"
+    );
+
+    let issue_key = input.issue_key.trim().to_uppercase();
+    let parent_key = input.parent_key.as_deref().map(|p| p.trim().to_uppercase());
+
+    let mut keys = vec![issue_key.clone()];
+    keys.extend(parent_key.clone());
+    let infos = fetch_hierarchy_info(&keys, "set_parent", ctx).await?;
+
+    let epic_link = epic_link_field_id(ctx).await?;
+    let method = reparent(&issue_key, parent_key.as_deref(), &infos, epic_link.as_deref(), ctx)
+        .await
+        .map_err(|e| log_err("set_parent", "reparent_failed", e))?;
+
+    tracing::info!(target: "mcp", tool = "set_parent", issue_key = %issue_key, method = %method, "Parent updated successfully");
+
+    Ok(CallToolResult::structured(json!({
+        "issue_key": issue_key,
+        "parent_key": parent_key,
+        "method": method
+    })))
+}
+
+pub async fn move_to_epic_handler(
+    input: MoveToEpicInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "move_to_epic",
+        epic_key = %input.epic_key,
+        issue_keys = ?input.issue_keys,
+        "Moving issues to epic"
+    );
+
+    if input.issue_keys.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params("issue_keys must not be empty", None));
+    }
+
+    let epic_key = input.epic_key.trim().to_uppercase();
+    let issue_keys: Vec<String> = input.issue_keys.iter().map(|k| k.trim().to_uppercase()).collect();
+
+    let mut keys = issue_keys.clone();
+    keys.push(epic_key.clone());
+    let infos = fetch_hierarchy_info(&keys, "move_to_epic", ctx).await?;
+
+    let epic = infos.get(&epic_key).ok_or_else(|| {
+        rmcp::ErrorData::invalid_params(format!("Issue {} not found", epic_key), None)
+    })?;
+
+    if hierarchy_level(epic) < 1 {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!(
+                "{} is a {}, not an epic",
+                epic_key,
+                issue_type_name(epic)
+            ),
+            None,
+        ));
+    }
+
+    let epic_link = epic_link_field_id(ctx).await?;
+    let mut results = Vec::new();
+
+    for key in &issue_keys {
+        let result = match reparent(key, Some(&epic_key), &infos, epic_link.as_deref(), ctx).await {
+            Ok(method) => BulkEditItemResult {
+                issue_key: key.clone(),
+                status: BulkItemStatus::Updated,
+                actions: vec![format!("parent set via {}", method)],
+                error: None,
+            },
+            Err(error) => BulkEditItemResult {
+                issue_key: key.clone(),
+                status: BulkItemStatus::Failed,
+                actions: Vec::new(),
+                error: Some(error),
+            },
+        };
+        results.push(result);
+    }
+
+    let moved = results.iter().filter(|r| r.status == BulkItemStatus::Updated).count();
+
+    tracing::info!(target: "mcp", tool = "move_to_epic", epic_key = %epic_key, moved = moved, "Issues moved to epic");
+
+    Ok(CallToolResult::structured(json!({
+        "epic_key": epic_key,
+        "moved": moved,
+        "failed": results.len() - moved,
+        "results": results
+    })))
+}
+
+/// Project, issue type and parent of each issue, keyed by issue key. JQL rejects the whole
+/// `key in (...)` query when one key is malformed or unknown, so a 400 falls back to one lookup
+/// per key and the bad keys are simply missing from the result.
+async fn fetch_hierarchy_info(
+    keys: &[String],
+    tool: &'static str,
+    ctx: &JiraCtx,
+) -> Result<HashMap<String, Value>, rmcp::ErrorData> {
+    const FIELDS: &str = "project,issuetype,parent";
+
+    let jql = format!("key in {}", quote_jql_list(keys));
+    let issues = match ctx
+        .client
+        .search_issues(&jql, Some(FIELDS), None, keys.len(), None, &ctx.auth)
+        .await
+    {
+        Ok(page) => page.issues,
+        Err(e) if is_bad_request(&e) => {
+            tracing::warn!(target: "mcp", tool = tool, error = %describe_error(&e), "Key lookup rejected, retrying per key");
+
+            let lookups: Vec<_> = keys
+                .iter()
+                .map(|key| {
+                    let jql = format!("key = {}", quote_jql_value(key));
+                    async move {
+                        match ctx.client.search_issues(&jql, Some(FIELDS), None, 1, None, &ctx.auth).await {
+                            Ok(page) => Ok(page.issues),
+                            Err(e) if is_bad_request(&e) => Ok(Vec::new()),
+                            Err(e) => Err(e),
+                        }
+                    }
+                })
+                .collect();
+
+            let mut issues = Vec::new();
+            for found in stream::iter(lookups).buffered(5).collect::<Vec<_>>().await {
+                issues.extend(found.map_err(|e| log_err(tool, "jira_error", e.to_string()))?);
+            }
+            issues
+        }
+        Err(e) => return Err(log_err(tool, "jira_error", e.to_string())),
+    };
+
+    Ok(issues
+        .into_iter()
+        .filter_map(|issue| {
+            let key = issue.get("key")?.as_str()?.to_string();
+            Some((key, issue.get("fields").cloned().unwrap_or(Value::Null)))
+        })
+        .collect())
+}
+
+fn is_bad_request(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<jira_client::error::JiraError>(),
+        Some(jira_client::error::JiraError::ApiError { status_code: 400, .. })
+    )
+}
+
+async fn epic_link_field_id(ctx: &JiraCtx) -> Result<Option<String>, rmcp::ErrorData> {
+    let fields = ctx
+        .cache
+        .fields(&ctx.client, &ctx.auth)
+        .await
+        .map_err(|e| log_err("set_parent", "jira_error", e.to_string()))?;
+
+    Ok(find_field(&fields, "Epic Link")
+        .filter(|f| f.custom)
+        .map(|f| f.id.clone()))
+}
+
+/// -1 for subtasks, 0 for standard issues, 1 for epics and above
+fn hierarchy_level(fields: &Value) -> i64 {
+    let issue_type = fields.get("issuetype");
+
+    if let Some(level) = issue_type.and_then(|t| t.get("hierarchyLevel")).and_then(|l| l.as_i64()) {
+        return level;
+    }
+
+    if issue_type.and_then(|t| t.get("subtask")).and_then(|s| s.as_bool()).unwrap_or(false) {
+        -1
+    } else if issue_type_name(fields).eq_ignore_ascii_case("Epic") {
+        1
+    } else {
+        0
+    }
+}
+
+fn issue_type_name(fields: &Value) -> &str {
+    fields
+        .get("issuetype")
+        .and_then(|t| t.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("issue")
+}
+
+/// Point an issue at a new parent (or none) and return how it was done.
+/// Company-managed projects that still use Epic Link reject `parent` for epics,
+/// so that case falls back to the Epic Link field.
+async fn reparent(
+    issue_key: &str,
+    parent_key: Option<&str>,
+    infos: &HashMap<String, Value>,
+    epic_link: Option<&str>,
+    ctx: &JiraCtx,
+) -> Result<&'static str, String> {
+    let issue = infos
+        .get(issue_key)
+        .ok_or_else(|| format!("Issue {} not found", issue_key))?;
+    let level = hierarchy_level(issue);
+
+    let parent = match parent_key {
+        Some(key) => Some(infos.get(key).ok_or_else(|| format!("Issue {} not found", key))?),
+        None => None,
+    };
+
+    if let (Some(parent), Some(key)) = (parent, parent_key) {
+        let parent_level = hierarchy_level(parent);
+
+        if parent_level <= level || (level < 0 && parent_level != 0) {
+            return Err(format!(
+                "A {} cannot be the parent of a {} ({})",
+                issue_type_name(parent),
+                issue_type_name(issue),
+                key
+            ));
+        }
+
+        if level < 0 {
+            let current = issue.get("parent").and_then(|p| p.get("key")).and_then(|k| k.as_str());
+            let project = |f: &Value| f.get("project").and_then(|p| p.get("key")).cloned();
+
+            if project(issue) != project(parent) {
+                return Err(format!("Subtask {} can only move to a parent in the same project", issue_key));
+            }
+
+            if current == Some(key) {
+                return Ok("unchanged");
+            }
+        }
+    }
+
+    let parent_value = match parent_key {
+        Some(key) => json!({ "key": key }),
+        None => Value::Null,
+    };
+
+    let payload = json!({ "fields": { "parent": parent_value } });
+    let parent_err = match ctx.client.update_issue(issue_key, &payload, &ctx.auth).await {
+        Ok(()) => return Ok("parent"),
+        Err(e) => e,
+    };
+
+    let is_epic_parent = parent.map(|p| hierarchy_level(p) == 1).unwrap_or(true);
+    let Some(epic_link) = epic_link.filter(|_| is_epic_parent && is_bad_request(&parent_err) && level == 0) else {
+        return Err(describe_error(&parent_err));
+    };
+
+    let mut fields = Map::new();
+    fields.insert(epic_link.to_string(), parent_key.map(|k| json!(k)).unwrap_or(Value::Null));
+
+    ctx.client
+        .update_issue(issue_key, &json!({ "fields": fields }), &ctx.auth)
+        .await
+        .map(|_| "epic_link")
+        .map_err(|e| describe_error(&e))
+}
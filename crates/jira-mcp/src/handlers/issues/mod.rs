@@ -1,7 +1,12 @@
+mod bulk;
+mod clone;
 mod crud;
 mod comments;
+mod hierarchy;
 mod labels;
 mod links;
+mod move_issue;
+mod similar;
 mod transitions;
 mod watchers;
 
@@ -13,6 +18,27 @@ pub use crud::{
     get_issue_handler,
 };
 
+pub(crate) use crud::run_search;
+
+pub use bulk::{
+    bulk_create_issues_handler,
+    bulk_edit_issues_handler,
+};
+
+pub use clone::clone_issue_handler;
+
+pub use move_issue::move_issue_handler;
+
+pub use similar::find_similar_issues_handler;
+pub(crate) use similar::description_text;
+
+pub use hierarchy::{
+    get_issue_tree_handler,
+    create_subtask_handler,
+    set_parent_handler,
+    move_to_epic_handler,
+};
+
 pub use comments::{
     add_comment_handler,
     get_comments_handler,
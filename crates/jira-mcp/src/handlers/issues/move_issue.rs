@@ -0,0 +1,391 @@
+use std::time::Duration;
+
+use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{describe_error, log_err, ResultMcpExt};
+use crate::models::MoveIssueInput;
+use crate::utils::{process_jira_fields, quote_jql_value};
+
+/// Fields every create screen carries, never reported as lost or missing
+const STRUCTURAL_FIELDS: &[&str] = &["project", "issuetype", "summary", "reporter", "parent"];
+
+const MOVE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MOVE_POLL_ATTEMPTS: usize = 30;
+
+pub async fn move_issue_handler(
+    input: MoveIssueInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "move_issue",
+        issue_key = %input.issue_key,
+        target_project = ?input.target_project,
+        target_issue_type = ?input.target_issue_type,
+        dry_run = input.dry_run,
+        "Moving issue"
+    );
+
+    let issue_key = input.issue_key.trim().to_uppercase();
+    let page = ctx
+        .client
+        .search_issues(
+            &format!("key = {}", quote_jql_value(&issue_key)),
+            Some("*all"),
+            None,
+            1,
+            None,
+            &ctx.auth,
+        )
+        .await
+        .mcp_context(error_ctx!("move_issue", "get issue").with_metadata("issue_key", issue_key.clone()))?;
+
+    let issue = page.issues.into_iter().next().ok_or_else(|| {
+        rmcp::ErrorData::invalid_params(format!("Issue {} not found", issue_key), None)
+    })?;
+    let fields = issue.get("fields").cloned().unwrap_or(Value::Null);
+    let text = |v: Option<&Value>| v.and_then(|s| s.as_str()).unwrap_or_default().to_string();
+
+    let issue_id = text(issue.get("id"));
+    let source_project = text(fields.get("project").and_then(|p| p.get("key")));
+    let source_type = text(fields.get("issuetype").and_then(|t| t.get("name")));
+    let is_subtask = fields
+        .get("issuetype")
+        .and_then(|t| t.get("subtask"))
+        .and_then(|s| s.as_bool())
+        .unwrap_or(false);
+    let source_status_id = text(fields.get("status").and_then(|s| s.get("id")));
+    let source_status = text(fields.get("status").and_then(|s| s.get("name")));
+    let source_category = text(
+        fields
+            .get("status")
+            .and_then(|s| s.get("statusCategory"))
+            .and_then(|c| c.get("key")),
+    );
+
+    let target_project = input
+        .target_project
+        .as_deref()
+        .map(|p| p.trim().to_uppercase())
+        .unwrap_or_else(|| source_project.clone());
+    let target_type = input
+        .target_issue_type
+        .clone()
+        .unwrap_or_else(|| source_type.clone());
+
+    if target_project == source_project && target_type.eq_ignore_ascii_case(&source_type) {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Target project and issue type are the same as the current ones; nothing to move",
+            None,
+        ));
+    }
+
+    if is_subtask && target_project != source_project {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("{} is a subtask; move its parent to change the project", issue_key),
+            None,
+        ));
+    }
+
+    let source_meta = ctx
+        .client
+        .get_createmeta(Some(&source_project), Some(&source_type), &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("move_issue", "get source createmeta"))?;
+    let target_meta = ctx
+        .client
+        .get_createmeta(Some(&target_project), Some(&target_type), &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("move_issue", "get target createmeta"))?;
+
+    let source_fields = createmeta_issue_type(&source_meta, &source_project)
+        .and_then(|t| t.get("fields").and_then(|f| f.as_object()).cloned())
+        .unwrap_or_default();
+
+    let Some(target_issue_type) = createmeta_issue_type(&target_meta, &target_project) else {
+        let available = ctx
+            .client
+            .list_issue_types(Some(&target_project), &ctx.auth)
+            .await
+            .map(|types| types.into_iter().map(|t| t.name).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        return Err(rmcp::ErrorData::invalid_params(
+            format!(
+                "Issue type '{}' is not available in project {}. Available: {}",
+                target_type,
+                target_project,
+                available.join(", ")
+            ),
+            None,
+        ));
+    };
+
+    let target_type_id = text(target_issue_type.get("id"));
+    let target_type_name = text(target_issue_type.get("name"));
+    let target_fields = target_issue_type
+        .get("fields")
+        .and_then(|f| f.as_object())
+        .cloned()
+        .unwrap_or_default();
+
+    let supplied = input
+        .target_fields
+        .as_ref()
+        .map(process_jira_fields)
+        .unwrap_or_default();
+
+    let has_value = |id: &str| match fields.get(id) {
+        None | Some(Value::Null) => false,
+        Some(Value::Array(arr)) => !arr.is_empty(),
+        Some(Value::String(s)) => !s.is_empty(),
+        Some(_) => true,
+    };
+
+    let lost_fields: Vec<Value> = source_fields
+        .iter()
+        .filter(|(id, _)| !STRUCTURAL_FIELDS.contains(&id.as_str()))
+        .filter(|(id, _)| has_value(id) && !target_fields.contains_key(*id))
+        .map(|(id, meta)| json!({ "id": id, "name": meta.get("name") }))
+        .collect();
+
+    let missing_required: Vec<Value> = target_fields
+        .iter()
+        .filter(|(id, _)| !STRUCTURAL_FIELDS.contains(&id.as_str()))
+        .filter(|(_, meta)| meta.get("required").and_then(|r| r.as_bool()).unwrap_or(false))
+        .filter(|(_, meta)| !meta.get("hasDefaultValue").and_then(|d| d.as_bool()).unwrap_or(false))
+        .filter(|(id, _)| !supplied.contains_key(*id))
+        .filter(|(id, _)| !(has_value(id) && source_fields.contains_key(*id)))
+        .map(|(id, meta)| {
+            let allowed: Vec<Value> = meta
+                .get("allowedValues")
+                .and_then(|a| a.as_array())
+                .map(|arr| {
+                    arr.iter()
+                        .take(20)
+                        .map(|v| v.get("name").or_else(|| v.get("value")).cloned().unwrap_or(Value::Null))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            json!({ "id": id, "name": meta.get("name"), "allowed_values": allowed })
+        })
+        .collect();
+
+    let statuses = ctx
+        .client
+        .get_project_statuses(&target_project, &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("move_issue", "get target statuses"))?;
+
+    let target_statuses: Vec<Value> = statuses
+        .iter()
+        .find(|t| t.get("id").and_then(|i| i.as_str()) == Some(target_type_id.as_str()))
+        .and_then(|t| t.get("statuses"))
+        .and_then(|s| s.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let status_name = |s: &Value| text(s.get("name"));
+    let (target_status, matched_by) = match input.target_status.as_deref() {
+        Some(wanted) => match target_statuses.iter().find(|s| status_name(s).eq_ignore_ascii_case(wanted)) {
+            Some(s) => (s, "requested"),
+            None => {
+                let names: Vec<String> = target_statuses.iter().map(status_name).collect();
+                return Err(rmcp::ErrorData::invalid_params(
+                    format!(
+                        "Status '{}' is not in the {} workflow of {}. Available: {}",
+                        wanted,
+                        target_type_name,
+                        target_project,
+                        names.join(", ")
+                    ),
+                    None,
+                ));
+            }
+        },
+        None => {
+            let by_name = target_statuses
+                .iter()
+                .find(|s| status_name(s).eq_ignore_ascii_case(&source_status))
+                .map(|s| (s, "name"));
+            let by_category = || {
+                target_statuses
+                    .iter()
+                    .find(|s| {
+                        s.get("statusCategory").and_then(|c| c.get("key")).and_then(|k| k.as_str())
+                            == Some(source_category.as_str())
+                    })
+                    .map(|s| (s, "status_category"))
+            };
+
+            by_name
+                .or_else(by_category)
+                .or_else(|| target_statuses.first().map(|s| (s, "default")))
+                .ok_or_else(|| {
+                    rmcp::ErrorData::internal_error(
+                        format!("No statuses found for {} in {}", target_type_name, target_project),
+                        None,
+                    )
+                })?
+        }
+    };
+
+    let status_mapping = json!({
+        "from": source_status,
+        "to": status_name(target_status),
+        "matched_by": matched_by
+    });
+
+    let report = json!({
+        "issue_key": issue_key,
+        "from": { "project": source_project, "issue_type": source_type },
+        "to": { "project": target_project, "issue_type": target_type_name },
+        "lost_fields": lost_fields,
+        "missing_required_fields": missing_required,
+        "status_mapping": status_mapping
+    });
+
+    if input.dry_run {
+        return Ok(CallToolResult::structured(json!({ "dry_run": true, "plan": report })));
+    }
+
+    if !missing_required.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            "The target requires fields the issue has no value for; pass them in target_fields",
+            Some(report),
+        ));
+    }
+
+    let mut mapping = json!({
+        "inferClassificationDefaults": true,
+        "inferFieldDefaults": true,
+        "inferStatusDefaults": false,
+        "inferSubtaskTypeDefault": true,
+        "issueIdsOrKeys": [issue_key],
+        "targetStatus": [{
+            "statuses": { text(target_status.get("id")): [source_status_id] }
+        }]
+    });
+
+    if !supplied.is_empty() {
+        let mandatory: Map<String, Value> = supplied
+            .iter()
+            .map(|(id, value)| {
+                let values = match value {
+                    Value::Array(arr) => arr.clone(),
+                    other => vec![other.clone()],
+                };
+                (id.clone(), json!({ "retain": false, "type": "raw", "value": values }))
+            })
+            .collect();
+        mapping["targetMandatoryFields"] = json!([{ "fields": mandatory }]);
+    }
+
+    let mut target_key = format!("{},{}", target_project, target_type_id);
+    if is_subtask {
+        if let Some(parent_id) = fields.get("parent").and_then(|p| p.get("id")).and_then(|i| i.as_str()) {
+            target_key = format!("{},{}", target_key, parent_id);
+        }
+    }
+
+    let mut targets = Map::new();
+    targets.insert(target_key, mapping);
+    let payload = json!({
+        "sendBulkNotification": true,
+        "targetToSourcesMapping": targets
+    });
+
+    let task_id = ctx
+        .client
+        .bulk_move_issues(&payload, &ctx.auth)
+        .await
+        .mcp_context(error_ctx!("move_issue", "submit move").with_metadata("issue_key", issue_key.clone()))?;
+
+    let task = wait_for_task(&task_id, ctx).await?;
+    let task_status = text(task.get("status"));
+
+    if task_status != "COMPLETE" {
+        return Ok(CallToolResult::structured(json!({
+            "moved": false,
+            "task_id": task_id,
+            "task_status": task_status,
+            "task": task,
+            "plan": report
+        })));
+    }
+
+    if let Some(failures) = task
+        .get("failedAccessibleIssues")
+        .and_then(|f| f.as_object())
+        .filter(|f| !f.is_empty())
+    {
+        return Err(rmcp::ErrorData::internal_error(
+            format!("Jira could not move {}", issue_key),
+            Some(json!({ "failures": failures, "plan": report })),
+        ));
+    }
+
+    // The issue id survives the move, the key does not
+    let new_key = ctx
+        .client
+        .search_issues(&format!("id = {}", issue_id), Some("summary"), None, 1, None, &ctx.auth)
+        .await
+        .ok()
+        .and_then(|p| p.issues.into_iter().next())
+        .and_then(|i| i.get("key").and_then(|k| k.as_str()).map(|k| k.to_string()))
+        .unwrap_or_else(|| issue_key.clone());
+
+    let url = ctx
+        .client
+        .base_url()
+        .join(&format!("/browse/{}", new_key))
+        .map(|u| u.to_string())
+        .unwrap_or_default();
+
+    tracing::info!(target: "mcp", tool = "move_issue", issue_key = %issue_key, new_key = %new_key, "Issue moved successfully");
+
+    Ok(CallToolResult::structured(json!({
+        "moved": true,
+        "issue_key": new_key,
+        "previous_key": issue_key,
+        "url": url,
+        "task_id": task_id,
+        "plan": report
+    })))
+}
+
+/// The single issue type entry createmeta returned for a project
+fn createmeta_issue_type<'a>(meta: &'a Value, project_key: &str) -> Option<&'a Value> {
+    meta.get("projects")?
+        .as_array()?
+        .iter()
+        .find(|p| p.get("key").and_then(|k| k.as_str()) == Some(project_key))?
+        .get("issuetypes")?
+        .as_array()?
+        .first()
+}
+
+async fn wait_for_task(task_id: &str, ctx: &JiraCtx) -> Result<Value, rmcp::ErrorData> {
+    let mut task = Value::Null;
+
+    for _ in 0..MOVE_POLL_ATTEMPTS {
+        tokio::time::sleep(MOVE_POLL_INTERVAL).await;
+
+        task = ctx
+            .client
+            .get_bulk_task(task_id, &ctx.auth)
+            .await
+            .map_err(|e| log_err("move_issue", "task_status_failed", describe_error(&e)))?;
+
+        let status = task.get("status").and_then(|s| s.as_str()).unwrap_or("");
+        if !matches!(status, "ENQUEUED" | "RUNNING" | "") {
+            break;
+        }
+    }
+
+    Ok(task)
+}
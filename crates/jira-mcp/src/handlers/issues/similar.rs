@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use jira_client::utils::adf_collect_text;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::ResultMcpExt;
+use crate::models::FindSimilarIssuesInput;
+use crate::utils::{issue_similarity, quote_jql_value, search_terms, text_tokens};
+
+/// How many text-search hits are scored locally
+const CANDIDATE_POOL: usize = 50;
+const MAX_SUMMARY_TERMS: usize = 6;
+const MAX_DESCRIPTION_TERMS: usize = 4;
+
+pub async fn find_similar_issues_handler(
+    input: FindSimilarIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "find_similar_issues",
+        project = %input.project,
+        summary = %input.summary,
+        "Searching for similar issues"
+    );
+
+    let matches = find_similar_issues(&input, ctx).await?;
+
+    Ok(CallToolResult::structured(json!({
+        "project": input.project,
+        "matches": matches,
+        "count": matches.len(),
+        "min_score": input.min_score
+    })))
+}
+
+/// Text-search the project for the summary/description terms and rank the hits by token overlap
+pub(crate) async fn find_similar_issues(
+    input: &FindSimilarIssuesInput,
+    ctx: &JiraCtx,
+) -> Result<Vec<Value>, rmcp::ErrorData> {
+    let summary_tokens = text_tokens(&input.summary);
+    let description_tokens = text_tokens(input.description.as_deref().unwrap_or_default());
+
+    if summary_tokens.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Summary has no searchable words",
+            None,
+        ));
+    }
+
+    let mut terms = search_terms(&summary_tokens, MAX_SUMMARY_TERMS);
+    for term in search_terms(&description_tokens, MAX_DESCRIPTION_TERMS + MAX_SUMMARY_TERMS) {
+        if terms.len() >= MAX_SUMMARY_TERMS + MAX_DESCRIPTION_TERMS {
+            break;
+        }
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+
+    let text_clauses: Vec<String> = terms
+        .iter()
+        .flat_map(|t| {
+            let quoted = quote_jql_value(t);
+            [format!("summary ~ {}", quoted), format!("description ~ {}", quoted)]
+        })
+        .collect();
+
+    let mut jql = format!(
+        "project = {} AND ({})",
+        quote_jql_value(&input.project),
+        text_clauses.join(" OR ")
+    );
+    if let Some(issue_type) = &input.issue_type {
+        jql.push_str(&format!(" AND issuetype = {}", quote_jql_value(issue_type)));
+    }
+    if !input.include_resolved {
+        jql.push_str(" AND resolution = EMPTY");
+    }
+    jql.push_str(" ORDER BY updated DESC");
+
+    let page = ctx
+        .client
+        .search_issues(
+            &jql,
+            Some("summary,description,status,issuetype,resolution,updated"),
+            None,
+            CANDIDATE_POOL,
+            None,
+            &ctx.auth,
+        )
+        .await
+        .mcp_context(
+            error_ctx!("find_similar_issues", "search candidates")
+                .with_metadata("project", input.project.clone())
+                .with_metadata("jql", jql.clone())
+        )?;
+
+    let base_url = ctx.client.base_url();
+    let mut scored: Vec<(f64, Value)> = page
+        .issues
+        .iter()
+        .filter_map(|issue| {
+            let key = issue.get("key")?.as_str()?;
+            let fields = issue.get("fields")?;
+            let summary = fields.get("summary").and_then(|s| s.as_str()).unwrap_or_default();
+
+            let candidate_summary = text_tokens(summary);
+            let candidate_description = text_tokens(&description_text(fields.get("description")));
+            let score = issue_similarity(
+                &summary_tokens,
+                &description_tokens,
+                &candidate_summary,
+                &candidate_description,
+            );
+
+            if score < input.min_score {
+                return None;
+            }
+
+            let mut matched: BTreeSet<&String> = summary_tokens.intersection(&candidate_summary).collect();
+            matched.extend(description_tokens.intersection(&candidate_description));
+
+            let url = base_url
+                .join(&format!("/browse/{}", key))
+                .map(|u| u.to_string())
+                .unwrap_or_default();
+
+            Some((
+                score,
+                json!({
+                    "key": key,
+                    "summary": summary,
+                    "status": fields.get("status").and_then(|s| s.get("name")),
+                    "issue_type": fields.get("issuetype").and_then(|t| t.get("name")),
+                    "resolution": fields.get("resolution").and_then(|r| r.get("name")),
+                    "updated": fields.get("updated"),
+                    "score": (score * 100.0).round() / 100.0,
+                    "matched_terms": matched,
+                    "url": url
+                }),
+            ))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored.truncate(input.limit);
+
+    tracing::info!(
+        target: "mcp",
+        tool = "find_similar_issues",
+        candidates = page.issues.len(),
+        matches = scored.len(),
+        "Ranked similar issues"
+    );
+
+    Ok(scored.into_iter().map(|(_, v)| v).collect())
+}
+
+/// Plain text of a description given as a string (v2) or ADF document (v3)
+pub(crate) fn description_text(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(doc @ Value::Object(_)) => {
+            let mut out = String::new();
+            adf_collect_text(doc, &mut out);
+            out
+        }
+        _ => String::new(),
+    }
+}
@@ -1,11 +1,17 @@
 use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
 
 use crate::context::JiraCtx;
-use crate::errors::{ResultMcpExt};
+use crate::errors::{describe_error, ResultMcpExt};
 use crate::errors::suggestions::get_transition_suggestions;
 use crate::models::{GetTransitionsInput, TransitionIssueInput, AssignIssueInput};
 use crate::error_ctx;
 use crate::handlers::error_utils::extract_error_message;
+use crate::utils::{
+    category_index, describe_screen_field, missing_required_fields, normalize_status_category, parse_transitions,
+    quote_jql_value, resolve_transition, screen_field_problems, status_match_rank, AvailableTransition, PlannedStep,
+    TransitionChoice, WorkflowGraph, CONFIDENT_MATCH_RANK,
+};
 
 pub async fn get_transitions_handler(
     input: GetTransitionsInput,
@@ -54,12 +60,23 @@ pub async fn get_transitions_handler(
                         .and_then(|v| v.as_str())
                         .unwrap_or("");
 
-                    serde_json::json!({
+                    let mut entry = serde_json::json!({
                         "id": id,
                         "name": name,
                         "to": to_name,
                         "category": category
-                    })
+                    });
+
+                    if let Some(fields) = t.get("fields").and_then(|f| f.as_object()) {
+                        let (required, optional): (Vec<Value>, Vec<Value>) = fields
+                            .iter()
+                            .map(|(field_id, meta)| describe_screen_field(field_id, meta))
+                            .partition(|f| f.get("required").and_then(|r| r.as_bool()).unwrap_or(false));
+                        entry["required_fields"] = json!(required);
+                        entry["optional_fields"] = json!(optional);
+                    }
+
+                    entry
                 })
                 .collect()
         })
@@ -87,15 +104,46 @@ pub async fn transition_issue_handler(
         target: "mcp",
         tool = "transition_issue",
         issue_key = %input.issue_key,
-        transition_id = %input.transition_id,
+        transition_id = ?input.transition_id,
+        to_status = ?input.to_status,
+        follow_path = input.follow_path,
         "Transitioning issue"
     );
 
+    let transition_id = match (&input.transition_id, &input.to_status) {
+        (Some(id), _) => id.clone(),
+        (None, Some(to_status)) => return transition_to_status(&input, to_status, ctx).await,
+        (None, None) => {
+            return Err(rmcp::ErrorData::invalid_params(
+                "Provide either transition_id or to_status",
+                None,
+            ));
+        }
+    };
+
+    let available = fetch_transitions(&input.issue_key, ctx).await?;
+    let transition = available.iter().find(|t| t.id == transition_id).ok_or_else(|| {
+        rmcp::ErrorData::invalid_params(
+            format!("Transition {} is not available for {}", transition_id, input.issue_key),
+            Some(json!({
+                "available_transitions": available.iter().map(|t| json!({ "id": t.id, "name": t.name, "to": t.to })).collect::<Vec<_>>()
+            })),
+        )
+    })?;
+
+    let problems = screen_field_problems(transition, &provided_fields(&input));
+    if !problems.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("Fields do not fit the '{}' transition screen", transition.name),
+            Some(json!({ "transition_id": transition_id, "problems": problems })),
+        ));
+    }
+
     let issue_key = input.issue_key.clone();
     ctx.client
         .transition_issue(
             &input.issue_key,
-            &input.transition_id,
+            &transition_id,
             input.fields.as_ref(),
             input.comment.as_deref(),
             &ctx.auth,
@@ -104,7 +152,7 @@ pub async fn transition_issue_handler(
         .mcp_context(
             error_ctx!("transition_issue", "transition issue")
                 .with_metadata("issue_key", input.issue_key.clone())
-                .with_metadata("transition_id", input.transition_id.clone())
+                .with_metadata("transition_id", transition_id.clone())
                 .with_suggestions(move |status| get_transition_suggestions(&issue_key, status))
         )?;
 
@@ -112,18 +160,345 @@ pub async fn transition_issue_handler(
         target: "mcp",
         tool = "transition_issue",
         issue_key = %input.issue_key,
-        transition_id = %input.transition_id,
+        transition_id = %transition_id,
         "Issue transitioned successfully"
     );
 
     Ok(CallToolResult::structured(serde_json::json!({
         "issue_key": input.issue_key,
-        "transition_id": input.transition_id,
+        "transition_id": transition_id,
         "success": true,
         "message": format!("Issue {} transitioned successfully", input.issue_key)
     })))
 }
 
+/// Most transitions a multi-hop walk takes before giving up
+const MAX_HOPS: usize = 8;
+
+/// How the next hop of a walk towards the target is chosen
+enum Route {
+    /// Statuses to pass through, from the workflow definition
+    Planned(Vec<PlannedStep>),
+    /// Workflow not readable: take one step towards the target's status category
+    TowardsCategory(usize),
+}
+
+async fn transition_to_status(
+    input: &TransitionIssueInput,
+    to_status: &str,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    let available = fetch_transitions(&input.issue_key, ctx).await?;
+
+    let issue_key = input.issue_key.trim().to_uppercase();
+    let page = ctx
+        .client
+        .search_issues(
+            &format!("key = {}", quote_jql_value(&issue_key)),
+            Some("status,project,issuetype"),
+            None,
+            1,
+            None,
+            &ctx.auth,
+        )
+        .await
+        .mcp_context(error_ctx!("transition_issue", "get issue").with_metadata("issue_key", issue_key.clone()))?;
+
+    let issue = page.issues.into_iter().next().ok_or_else(|| {
+        rmcp::ErrorData::invalid_params(format!("Issue {} not found", issue_key), None)
+    })?;
+    let fields = issue.get("fields").cloned().unwrap_or(Value::Null);
+    let text = |v: Option<&Value>| v.and_then(|s| s.as_str()).unwrap_or_default().to_string();
+
+    let status_id = text(fields.get("status").and_then(|s| s.get("id")));
+    let status_name = text(fields.get("status").and_then(|s| s.get("name")));
+    let project_id = text(fields.get("project").and_then(|p| p.get("id")));
+    let project_key = text(fields.get("project").and_then(|p| p.get("key")));
+    let issue_type_id = text(fields.get("issuetype").and_then(|t| t.get("id")));
+
+    if status_match_rank(to_status, &status_name, "").is_some_and(|r| r <= 1) {
+        return Ok(already_in_status(&issue_key, to_status, &status_name));
+    }
+
+    match resolve_transition(&available, to_status) {
+        TransitionChoice::Confident(_) => {
+            return walk_to_status(input, to_status, available, None, "direct", ctx).await;
+        }
+        TransitionChoice::Candidates(candidates) => {
+            return Ok(CallToolResult::structured(json!({
+                "issue_key": issue_key,
+                "from_status": status_name,
+                "to_status": to_status,
+                "executed": false,
+                "candidates": candidates.iter().map(|t| json!({ "id": t.id, "name": t.name, "to": t.to })).collect::<Vec<_>>(),
+                "hint": "No clear match for the requested status. Call again with transition_id or the exact status name"
+            })));
+        }
+        TransitionChoice::NoMatch => {}
+    }
+
+    let planned = match ctx.client.get_issue_workflow(&project_id, &issue_type_id, &ctx.auth).await {
+        Ok(response) => WorkflowGraph::from_response(&response)
+            .and_then(|graph| graph.shortest_path(&status_id, to_status)),
+        Err(e) => {
+            tracing::warn!(
+                target: "mcp",
+                tool = "transition_issue",
+                error = %describe_error(&e),
+                "Workflow not readable, falling back to status categories"
+            );
+            None
+        }
+    };
+
+    let unreachable = |hint: &str| {
+        rmcp::ErrorData::invalid_params(
+            format!("Status '{}' is not reachable from '{}'. {}", to_status, status_name, hint),
+            Some(json!({
+                "issue_key": issue_key,
+                "current_status": status_name,
+                "available_transitions": available.iter().map(|t| json!({ "id": t.id, "name": t.name, "to": t.to })).collect::<Vec<_>>()
+            })),
+        )
+    };
+
+    if let Some((plan, rank)) = planned {
+        if plan.is_empty() {
+            return Ok(already_in_status(&issue_key, to_status, &status_name));
+        }
+
+        let confident = rank <= CONFIDENT_MATCH_RANK;
+        if !input.follow_path || !confident {
+            let first_required = available
+                .iter()
+                .find(|t| plan.first().is_some_and(|p| t.to.eq_ignore_ascii_case(&p.to)))
+                .map(|t| missing_required_fields(t, &provided_fields(input)))
+                .unwrap_or_default();
+
+            return Ok(CallToolResult::structured(json!({
+                "issue_key": issue_key,
+                "from_status": status_name,
+                "to_status": plan.last().map(|p| p.to.clone()),
+                "executed": false,
+                "path_source": "workflow",
+                "path": plan.iter().map(|p| json!({ "transition": p.transition, "from": p.from, "to": p.to })).collect::<Vec<_>>(),
+                "first_step_required_fields": first_required,
+                "hint": if confident {
+                    "Not directly reachable. Call again with follow_path=true to run these transitions"
+                } else {
+                    "The requested status only loosely matches this target. Call again with the exact status name to run these transitions"
+                }
+            })));
+        }
+
+        return walk_to_status(input, to_status, available, Some(Route::Planned(plan)), "workflow", ctx).await;
+    }
+
+    if !input.follow_path {
+        return Err(unreachable("Pass follow_path=true to walk through intermediate statuses"));
+    }
+
+    let target_category = match normalize_status_category(to_status) {
+        Some(category) => category.to_string(),
+        None => {
+            let statuses = ctx
+                .client
+                .get_project_statuses(&project_key, &ctx.auth)
+                .await
+                .mcp_context(error_ctx!("transition_issue", "get project statuses").with_metadata("project", project_key.clone()))?;
+
+            statuses
+                .iter()
+                .filter_map(|it| it.get("statuses").and_then(|s| s.as_array()))
+                .flatten()
+                .find(|s| {
+                    let name = s.get("name").and_then(|n| n.as_str()).unwrap_or_default();
+                    status_match_rank(to_status, name, "").is_some_and(|r| r <= 1)
+                })
+                .map(|s| text(s.get("statusCategory").and_then(|c| c.get("name"))))
+                .ok_or_else(|| unreachable("No status with that name exists in the project"))?
+        }
+    };
+    let target_index = category_index(&target_category).ok_or_else(|| unreachable("Unknown status category"))?;
+
+    walk_to_status(input, to_status, available, Some(Route::TowardsCategory(target_index)), "category", ctx).await
+}
+
+async fn fetch_transitions(issue_key: &str, ctx: &JiraCtx) -> Result<Vec<AvailableTransition>, rmcp::ErrorData> {
+    let key = issue_key.to_string();
+    let result = ctx
+        .client
+        .get_transitions(issue_key, Some("transitions.fields"), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("transition_issue", "get transitions")
+                .with_metadata("issue_key", issue_key.to_string())
+                .with_suggestions(move |status| get_transition_suggestions(&key, status))
+        )?;
+
+    Ok(parse_transitions(&result))
+}
+
+/// Success without any transition when the issue already sits in the requested status
+fn already_in_status(issue_key: &str, to_status: &str, status_name: &str) -> CallToolResult {
+    CallToolResult::structured(json!({
+        "issue_key": issue_key,
+        "to_status": to_status,
+        "reached": true,
+        "final_status": status_name,
+        "steps": [],
+        "success": true,
+        "message": format!("Issue {} is already in status '{}'", issue_key, status_name)
+    }))
+}
+
+fn provided_fields(input: &TransitionIssueInput) -> Map<String, Value> {
+    input.fields.as_ref().and_then(|f| f.as_object()).cloned().unwrap_or_default()
+}
+
+/// Run transitions until the target is reached, stopping early when a screen needs fields that were not supplied
+async fn walk_to_status(
+    input: &TransitionIssueInput,
+    to_status: &str,
+    mut available: Vec<AvailableTransition>,
+    route: Option<Route>,
+    path_source: &str,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    let provided = provided_fields(input);
+    let mut used_fields: Vec<String> = Vec::new();
+    let mut steps: Vec<Value> = Vec::new();
+    let mut reached = false;
+    let mut stop_reason: Option<String> = None;
+
+    for hop in 0..MAX_HOPS {
+        let direct = match resolve_transition(&available, to_status) {
+            TransitionChoice::Confident(t) => Some(t.clone()),
+            _ => None,
+        };
+        let is_final = direct.is_some();
+
+        // Without the workflow each hop is a guess, so only one is taken per call
+        if hop > 0 && !is_final && matches!(route, Some(Route::TowardsCategory(_))) {
+            stop_reason = Some(
+                "Workflow not readable: took one step towards the target's status category. Call again to continue"
+                    .to_string(),
+            );
+            break;
+        }
+
+        let next = direct.or_else(|| match &route {
+            Some(Route::Planned(plan)) => plan
+                .get(hop)
+                .and_then(|step| available.iter().find(|t| t.to.eq_ignore_ascii_case(&step.to)))
+                .cloned(),
+            Some(Route::TowardsCategory(target)) => available
+                .iter()
+                .min_by_key(|t| category_index(&t.category).map_or(usize::MAX, |c| c.abs_diff(*target)))
+                .cloned(),
+            None => None,
+        });
+
+        let Some(transition) = next else {
+            stop_reason = Some("No transition leads further towards the target".to_string());
+            break;
+        };
+
+        let screen: Map<String, Value> = provided
+            .iter()
+            .filter(|(id, _)| transition.fields.contains_key(*id))
+            .map(|(id, v)| (id.clone(), v.clone()))
+            .collect();
+
+        let problems = screen_field_problems(&transition, &screen);
+        if !problems.is_empty() {
+            steps.push(json!({
+                "transition_id": transition.id,
+                "transition": transition.name,
+                "to": transition.to,
+                "executed": false,
+                "problems": problems
+            }));
+            stop_reason = Some(format!("Fields do not fit the '{}' transition screen", transition.name));
+            break;
+        }
+
+        used_fields.extend(screen.keys().cloned());
+        let screen_fields = (!screen.is_empty()).then_some(Value::Object(screen));
+
+        let issue_key = input.issue_key.clone();
+        ctx.client
+            .transition_issue(
+                &input.issue_key,
+                &transition.id,
+                screen_fields.as_ref(),
+                if is_final { input.comment.as_deref() } else { None },
+                &ctx.auth,
+            )
+            .await
+            .mcp_context(
+                error_ctx!("transition_issue", "transition issue")
+                    .with_metadata("issue_key", input.issue_key.clone())
+                    .with_metadata("transition_id", transition.id.clone())
+                    .with_metadata("completed_steps", steps.len().to_string())
+                    .with_suggestions(move |status| get_transition_suggestions(&issue_key, status))
+            )?;
+
+        tracing::info!(
+            target: "mcp",
+            tool = "transition_issue",
+            issue_key = %input.issue_key,
+            transition = %transition.name,
+            to = %transition.to,
+            "Transition step executed"
+        );
+
+        steps.push(json!({
+            "transition_id": transition.id,
+            "transition": transition.name,
+            "to": transition.to,
+            "executed": true,
+            "screen_fields": screen_fields.as_ref().and_then(|f| f.as_object()).map(|f| f.keys().cloned().collect::<Vec<_>>())
+        }));
+
+        if is_final {
+            reached = true;
+            break;
+        }
+
+        available = fetch_transitions(&input.issue_key, ctx).await?;
+    }
+
+    if !reached && stop_reason.is_none() {
+        stop_reason = Some(format!("Gave up after {} transitions", MAX_HOPS));
+    }
+
+    let unused_fields: Vec<&String> = provided.keys().filter(|k| !used_fields.contains(k)).collect();
+    let final_status = steps
+        .iter()
+        .rev()
+        .find(|s| s.get("executed").and_then(|e| e.as_bool()) == Some(true))
+        .and_then(|s| s.get("to").cloned());
+
+    let mut response = json!({
+        "issue_key": input.issue_key,
+        "to_status": to_status,
+        "reached": reached,
+        "final_status": final_status,
+        "path_source": path_source,
+        "steps": steps,
+        "success": reached
+    });
+    if let Some(reason) = stop_reason {
+        response["stopped"] = json!(reason);
+    }
+    if !unused_fields.is_empty() {
+        response["unused_fields"] = json!(unused_fields);
+    }
+
+    Ok(CallToolResult::structured(response))
+}
+
 pub async fn assign_issue_handler(
     input: AssignIssueInput,
     ctx: &JiraCtx,
@@ -0,0 +1,422 @@
+use anyhow::Result;
+use rmcp::model::CallToolResult;
+use serde_json::Value;
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{log_err, ResultMcpExt};
+use crate::errors::suggestions::get_jql_suggestions;
+use crate::models::{BuildJqlInput, JqlAutocompleteInput, ValidateJqlInput};
+use crate::utils::{
+    date_before_clause, field_clause_name, find_field, flatten_jql_clauses, is_text_field, looks_like_account_id,
+    normalize_status_category, quote_jql_list, quote_jql_value, split_order_direction, sprint_clause,
+    strip_highlight,
+};
+
+pub async fn build_jql_handler(
+    input: BuildJqlInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "build_jql", input = ?input, "Building JQL");
+
+    let mut clauses: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+
+    if let Some(project) = input.project.as_deref() {
+        let projects = ctx
+            .cache
+            .projects(&ctx.client, &ctx.auth)
+            .await
+            .map_err(|e| log_err("build_jql", "jira_error", e.to_string()))?;
+
+        let resolved = projects
+            .iter()
+            .find(|p| p.key.eq_ignore_ascii_case(project))
+            .or_else(|| projects.iter().find(|p| p.name.eq_ignore_ascii_case(project)));
+
+        match resolved {
+            Some(p) => clauses.push(format!("project = {}", quote_jql_value(&p.key))),
+            None => {
+                warnings.push(format!("Project '{}' not found among accessible projects", project));
+                clauses.push(format!("project = {}", quote_jql_value(project)));
+            }
+        }
+    }
+
+    if let Some(types) = input.issue_types.as_ref().filter(|t| !t.is_empty()) {
+        clauses.push(format!("issuetype in {}", quote_jql_list(types)));
+    }
+
+    if let Some(statuses) = input.statuses.as_ref().filter(|s| !s.is_empty()) {
+        clauses.push(format!("status in {}", quote_jql_list(statuses)));
+    }
+
+    if let Some(category) = input.status_category.as_deref() {
+        match normalize_status_category(category) {
+            Some(name) => clauses.push(format!("statusCategory = {}", quote_jql_value(name))),
+            None => {
+                warnings.push(format!(
+                    "Unknown status category '{}'; expected To Do, In Progress or Done",
+                    category
+                ));
+                clauses.push(format!("statusCategory = {}", quote_jql_value(category)));
+            }
+        }
+    }
+
+    if let Some(assignee) = input.assignee.as_deref() {
+        let clause = resolve_assignee_clause(assignee, ctx, &mut warnings).await;
+        clauses.push(clause);
+    }
+
+    if let Some(labels) = input.labels.as_ref().filter(|l| !l.is_empty()) {
+        clauses.push(format!("labels in {}", quote_jql_list(labels)));
+    }
+
+    if let Some(sprint) = input.sprint.as_deref() {
+        clauses.push(sprint_clause(sprint));
+    }
+
+    for (field, value) in [("created", &input.created_after), ("updated", &input.updated_after)] {
+        if let Some(v) = value {
+            clauses.push(format!("{} >= {}", field, quote_jql_value(v)));
+        }
+    }
+
+    for (field, value) in [("created", &input.created_before), ("updated", &input.updated_before)] {
+        if let Some(v) = value {
+            clauses.push(date_before_clause(field, v));
+        }
+    }
+
+    if let Some(text) = input.text.as_deref().filter(|t| !t.trim().is_empty()) {
+        clauses.push(format!("text ~ {}", quote_jql_value(text)));
+    }
+
+    let needs_fields = input.custom_fields.as_ref().map(|c| !c.is_empty()).unwrap_or(false)
+        || input.order_by.as_ref().map(|o| !o.is_empty()).unwrap_or(false);
+
+    let fields = if needs_fields {
+        ctx.cache
+            .fields(&ctx.client, &ctx.auth)
+            .await
+            .map_err(|e| log_err("build_jql", "jira_error", e.to_string()))?
+    } else {
+        Vec::new()
+    };
+
+    if let Some(custom) = input.custom_fields.as_ref() {
+        for (name, value) in custom {
+            let field = find_field(&fields, name);
+            let clause_name = match field {
+                Some(f) => field_clause_name(f),
+                None => {
+                    warnings.push(format!("Field '{}' not found; using its name as given", name));
+                    quote_jql_value(name)
+                }
+            };
+            let text_match = field.map(is_text_field).unwrap_or(false);
+
+            match value_clause(&clause_name, value, text_match) {
+                Some(clause) => clauses.push(clause),
+                None => warnings.push(format!("Unsupported value for field '{}': {}", name, value)),
+            }
+        }
+    }
+
+    let mut jql = clauses.join(" AND ");
+
+    if let Some(order_by) = input.order_by.as_ref().filter(|o| !o.is_empty()) {
+        let terms: Vec<String> = order_by
+            .iter()
+            .map(|entry| {
+                let (name, direction) = split_order_direction(entry);
+                let field_name = match find_field(&fields, name) {
+                    Some(f) => field_clause_name(f),
+                    None if name.contains(' ') => quote_jql_value(name),
+                    None => name.to_string(),
+                };
+                match direction {
+                    Some(dir) => format!("{} {}", field_name, dir),
+                    None => field_name,
+                }
+            })
+            .collect();
+
+        if !jql.is_empty() {
+            jql.push(' ');
+        }
+        jql.push_str(&format!("ORDER BY {}", terms.join(", ")));
+    }
+
+    tracing::info!(target: "mcp", tool = "build_jql", jql = %jql, warnings = warnings.len(), "JQL built");
+
+    Ok(CallToolResult::structured(serde_json::json!({
+        "jql": jql,
+        "clauses": clauses,
+        "warnings": warnings
+    })))
+}
+
+async fn resolve_assignee_clause(assignee: &str, ctx: &JiraCtx, warnings: &mut Vec<String>) -> String {
+    let trimmed = assignee.trim();
+
+    match trimmed.to_lowercase().as_str() {
+        "me" | "currentuser" | "currentuser()" => return "assignee = currentUser()".to_string(),
+        "unassigned" | "none" | "empty" => return "assignee is EMPTY".to_string(),
+        _ => {}
+    }
+
+    if looks_like_account_id(trimmed) {
+        return format!("assignee = {}", quote_jql_value(trimmed));
+    }
+
+    match ctx.client.search_users(trimmed, Some(10), &ctx.auth).await {
+        Ok(users) => {
+            let exact = users
+                .iter()
+                .find(|u| u.display_name.eq_ignore_ascii_case(trimmed));
+
+            match (exact, users.len()) {
+                (Some(u), _) => format!("assignee = {}", quote_jql_value(&u.account_id)),
+                (None, 1) => format!("assignee = {}", quote_jql_value(&users[0].account_id)),
+                (None, 0) => {
+                    warnings.push(format!("No user found matching '{}'", trimmed));
+                    format!("assignee = {}", quote_jql_value(trimmed))
+                }
+                (None, n) => {
+                    warnings.push(format!(
+                        "'{}' matches {} users; using the first ({}). Pass an account id to be exact",
+                        trimmed, n, users[0].display_name
+                    ));
+                    format!("assignee = {}", quote_jql_value(&users[0].account_id))
+                }
+            }
+        }
+        Err(e) => {
+            warnings.push(format!("Could not resolve user '{}': {}", trimmed, e));
+            format!("assignee = {}", quote_jql_value(trimmed))
+        }
+    }
+}
+
+fn value_clause(field: &str, value: &Value, text_match: bool) -> Option<String> {
+    match value {
+        Value::String(s) if text_match => Some(format!("{} ~ {}", field, quote_jql_value(s))),
+        Value::String(s) => Some(format!("{} = {}", field, quote_jql_value(s))),
+        Value::Number(n) => Some(format!("{} = {}", field, n)),
+        Value::Bool(b) => Some(format!("{} = {}", field, quote_jql_value(&b.to_string()))),
+        Value::Null => Some(format!("{} is EMPTY", field)),
+        Value::Array(items) => {
+            let values: Vec<String> = items
+                .iter()
+                .map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+
+            if values.is_empty() {
+                None
+            } else {
+                Some(format!("{} in {}", field, quote_jql_list(&values)))
+            }
+        }
+        Value::Object(_) => None,
+    }
+}
+
+pub async fn validate_jql_handler(
+    input: ValidateJqlInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    let validation = input.validation.as_deref().unwrap_or("strict");
+    tracing::info!(target: "mcp", tool = "validate_jql", jql = %input.jql, validation = %validation);
+
+    let jql = input.jql.clone();
+    let response = ctx
+        .client
+        .parse_jql(std::slice::from_ref(&input.jql), validation, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("validate_jql", "parse JQL")
+                .with_metadata("jql", input.jql.clone())
+                .with_suggestions(move |status| get_jql_suggestions(&jql, status))
+        )?;
+
+    let parsed = response
+        .get("queries")
+        .and_then(|q| q.as_array())
+        .and_then(|q| q.first())
+        .cloned()
+        .unwrap_or_default();
+
+    let errors: Vec<String> = parsed
+        .get("errors")
+        .and_then(|e| e.as_array())
+        .map(|arr| arr.iter().filter_map(|e| e.as_str()).map(|e| e.to_string()).collect())
+        .unwrap_or_default();
+
+    let structure = parsed.get("structure").cloned().unwrap_or(Value::Null);
+
+    let mut clauses = Vec::new();
+
+    if let Some(where_clause) = structure.get("where") {
+        flatten_jql_clauses(where_clause, &mut clauses);
+    }
+
+    let mut matched = vec![false; errors.len()];
+
+    for clause in clauses.iter_mut() {
+        let field = clause.get("field").and_then(|f| f.as_str()).unwrap_or("").to_lowercase();
+        let needle = format!("'{}'", field);
+        let clause_errors: Vec<&String> = errors
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !field.is_empty() && e.to_lowercase().contains(&needle))
+            .map(|(i, e)| {
+                matched[i] = true;
+                e
+            })
+            .collect();
+
+        clause["errors"] = serde_json::json!(clause_errors);
+    }
+
+    let general_errors: Vec<&String> = errors
+        .iter()
+        .zip(matched.iter())
+        .filter(|(_, m)| !**m)
+        .map(|(e, _)| e)
+        .collect();
+
+    tracing::info!(
+        target: "mcp",
+        tool = "validate_jql",
+        valid = errors.is_empty(),
+        errors = errors.len(),
+        "JQL validated"
+    );
+
+    Ok(CallToolResult::structured(serde_json::json!({
+        "jql": input.jql,
+        "valid": errors.is_empty(),
+        "errors": errors,
+        "general_errors": general_errors,
+        "clauses": clauses,
+        "order_by": structure.get("orderBy").cloned().unwrap_or(Value::Null)
+    })))
+}
+
+pub async fn jql_autocomplete_handler(
+    input: JqlAutocompleteInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "jql_autocomplete",
+        field_name = ?input.field_name,
+        query = ?input.query,
+        "Getting JQL autocomplete suggestions"
+    );
+
+    if let Some(field_name) = input.field_name.as_deref() {
+        let results = ctx
+            .client
+            .get_jql_value_suggestions(field_name, input.query.as_deref(), &ctx.auth)
+            .await
+            .mcp_context(
+                error_ctx!("jql_autocomplete", "get JQL value suggestions")
+                    .with_metadata("field_name", field_name.to_string())
+            )?;
+
+        let suggestions: Vec<Value> = results
+            .iter()
+            .take(input.max_results)
+            .map(|r| {
+                let value = r.get("value").and_then(|v| v.as_str()).unwrap_or("");
+                let display = r.get("displayName").and_then(|v| v.as_str()).unwrap_or(value);
+                serde_json::json!({
+                    "value": value,
+                    "display_name": strip_highlight(display),
+                    "jql": quote_jql_value(value)
+                })
+            })
+            .collect();
+
+        return Ok(CallToolResult::structured(serde_json::json!({
+            "field_name": field_name,
+            "suggestions": suggestions,
+            "count": suggestions.len()
+        })));
+    }
+
+    let data = ctx
+        .cache
+        .jql_autocomplete(&ctx.client, &ctx.auth)
+        .await
+        .map_err(|e| log_err("jql_autocomplete", "jira_error", e.to_string()))?;
+
+    let query = input.query.as_deref().unwrap_or("").trim().to_lowercase();
+    let matches = |entry: &Value| {
+        query.is_empty()
+            || ["value", "displayName"].iter().any(|k| {
+                entry
+                    .get(*k)
+                    .and_then(|v| v.as_str())
+                    .map(|v| v.to_lowercase().contains(&query))
+                    .unwrap_or(false)
+            })
+    };
+
+    let fields: Vec<Value> = data
+        .get("visibleFieldNames")
+        .and_then(|f| f.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter(|f| matches(f))
+                .take(input.max_results)
+                .map(|f| {
+                    serde_json::json!({
+                        "value": f.get("value").cloned().unwrap_or(Value::Null),
+                        "display_name": strip_highlight(f.get("displayName").and_then(|v| v.as_str()).unwrap_or("")),
+                        "operators": f.get("operators").cloned().unwrap_or(Value::Null),
+                        "types": f.get("types").cloned().unwrap_or(Value::Null),
+                        "cfid": f.get("cfid").cloned().unwrap_or(Value::Null)
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let functions: Vec<Value> = data
+        .get("visibleFunctionNames")
+        .and_then(|f| f.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter(|f| matches(f))
+                .take(input.max_results)
+                .map(|f| {
+                    serde_json::json!({
+                        "value": f.get("value").cloned().unwrap_or(Value::Null),
+                        "is_list": f.get("isList").cloned().unwrap_or(Value::Null),
+                        "types": f.get("types").cloned().unwrap_or(Value::Null)
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    tracing::info!(
+        target: "mcp",
+        tool = "jql_autocomplete",
+        fields = fields.len(),
+        functions = functions.len(),
+        "JQL autocomplete data filtered"
+    );
+
+    Ok(CallToolResult::structured(serde_json::json!({
+        "fields": fields,
+        "functions": functions
+    })))
+}
@@ -1,13 +1,27 @@
+pub mod boards;
+pub mod components;
 pub mod error_utils;
 pub mod issues;
 pub mod fields;
+pub mod filters;
+pub mod jql;
 pub mod metadata;
 pub mod projects;
+pub mod sprints;
+pub mod templates;
 pub mod users;
+pub mod versions;
 
+pub use boards::*;
+pub use components::*;
 pub use error_utils::*;
 pub use issues::*;
 pub use fields::*;
+pub use filters::*;
+pub use jql::*;
 pub use metadata::*;
 pub use projects::*;
+pub use sprints::*;
+pub use templates::*;
 pub use users::*;
+pub use versions::*;
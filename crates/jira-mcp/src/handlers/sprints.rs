@@ -0,0 +1,1007 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt};
+use jira_client::models::Sprint;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::suggestions::get_sprint_suggestions;
+use crate::board_utils::board_estimation_field;
+use crate::errors::{describe_error, HandlerErrorContext, ResultMcpExt};
+use crate::models::{
+    BoardVelocityInput, CompleteSprintInput, CreateSprintInput, DeleteSprintInput, IncompleteIssuesTarget, SprintReportInput,
+    StartSprintInput, UpdateSprintInput,
+};
+use crate::utils::{
+    changelog_items, format_jira_datetime, in_sprint_at, numeric_value_at, parse_jira_datetime, quote_jql_value,
+    sprint_date_param, sprint_membership_changes, status_category_name,
+};
+
+/// The Agile API moves at most this many issues per request
+const MOVE_CHUNK: usize = 50;
+const MAX_SPRINT_ISSUES: usize = 1000;
+
+fn date_param(field: &str, value: &str) -> Result<String, rmcp::ErrorData> {
+    sprint_date_param(value).ok_or_else(|| {
+        rmcp::ErrorData::invalid_params(
+            format!("{} '{}' is not a date (YYYY-MM-DD) or ISO 8601 timestamp", field, value),
+            None,
+        )
+    })
+}
+
+/// Sprint fields shared by create and update; only the given ones are included
+fn sprint_payload(
+    name: Option<&str>,
+    goal: Option<&str>,
+    start_date: Option<&str>,
+    end_date: Option<&str>,
+) -> Result<Map<String, Value>, rmcp::ErrorData> {
+    let mut payload = Map::new();
+
+    if let Some(name) = name {
+        payload.insert("name".into(), json!(name));
+    }
+    if let Some(goal) = goal {
+        payload.insert("goal".into(), json!(goal));
+    }
+    if let Some(start) = start_date {
+        payload.insert("startDate".into(), json!(date_param("start_date", start)?));
+    }
+    if let Some(end) = end_date {
+        payload.insert("endDate".into(), json!(date_param("end_date", end)?));
+    }
+
+    Ok(payload)
+}
+
+async fn fetch_sprint(tool: &'static str, sprint_id: u64, ctx: &JiraCtx) -> Result<Sprint, rmcp::ErrorData> {
+    ctx.client
+        .get_sprint(sprint_id, &ctx.auth)
+        .await
+        .mcp_context(
+            HandlerErrorContext::new(tool, "get sprint")
+                .with_metadata("sprint_id", sprint_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )
+}
+
+fn require_state(sprint: &Sprint, state: &str, action: &str) -> Result<(), rmcp::ErrorData> {
+    if sprint.state.eq_ignore_ascii_case(state) {
+        return Ok(());
+    }
+
+    Err(rmcp::ErrorData::invalid_params(
+        format!(
+            "Sprint {} ('{}') is {}; only {} sprints can be {}",
+            sprint.id, sprint.name, sprint.state, state, action
+        ),
+        None,
+    ))
+}
+
+pub async fn create_sprint_handler(
+    input: CreateSprintInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "create_sprint",
+        board_id = input.board_id,
+        name = %input.name,
+        "Creating sprint"
+    );
+
+    let mut payload = sprint_payload(
+        Some(&input.name),
+        input.goal.as_deref(),
+        input.start_date.as_deref(),
+        input.end_date.as_deref(),
+    )?;
+    payload.insert("originBoardId".into(), json!(input.board_id));
+
+    let sprint = ctx
+        .client
+        .create_sprint(&Value::Object(payload), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_sprint", "create sprint")
+                .with_metadata("board_id", input.board_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+
+    tracing::info!(target: "mcp", tool = "create_sprint", sprint_id = sprint.id, "Sprint created");
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "sprint": sprint
+    })))
+}
+
+pub async fn update_sprint_handler(
+    input: UpdateSprintInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "update_sprint", sprint_id = input.sprint_id, "Updating sprint");
+
+    let payload = sprint_payload(
+        input.name.as_deref(),
+        input.goal.as_deref(),
+        input.start_date.as_deref(),
+        input.end_date.as_deref(),
+    )?;
+
+    if payload.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Nothing to update: pass name, goal, start_date or end_date",
+            None,
+        ));
+    }
+
+    let updated_fields: Vec<String> = payload.keys().cloned().collect();
+    let sprint = ctx
+        .client
+        .update_sprint(input.sprint_id, &Value::Object(payload), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("update_sprint", "update sprint")
+                .with_metadata("sprint_id", input.sprint_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "sprint": sprint,
+        "updated_fields": updated_fields
+    })))
+}
+
+pub async fn start_sprint_handler(
+    input: StartSprintInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "start_sprint", sprint_id = input.sprint_id, "Starting sprint");
+
+    let sprint = fetch_sprint("start_sprint", input.sprint_id, ctx).await?;
+    require_state(&sprint, "future", "started")?;
+
+    let start = match &input.start_date {
+        Some(s) => date_param("start_date", s)?,
+        None => format_jira_datetime(Utc::now()),
+    };
+
+    let end = match (&input.end_date, &sprint.end_date) {
+        (Some(e), _) => date_param("end_date", e)?,
+        (None, Some(planned)) if parse_jira_datetime(planned).is_some_and(|p| p > Utc::now()) => planned.clone(),
+        _ => {
+            let start_at = parse_jira_datetime(&start).unwrap_or_else(Utc::now);
+            format_jira_datetime(start_at + Duration::days(input.duration_days))
+        }
+    };
+
+    if parse_jira_datetime(&end) <= parse_jira_datetime(&start) {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("End date {} is not after start date {}", end, start),
+            None,
+        ));
+    }
+
+    let mut payload = json!({
+        "state": "active",
+        "startDate": start,
+        "endDate": end
+    });
+    if let Some(goal) = &input.goal {
+        payload["goal"] = json!(goal);
+    }
+
+    let started = ctx
+        .client
+        .update_sprint(input.sprint_id, &payload, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("start_sprint", "start sprint")
+                .with_metadata("sprint_id", input.sprint_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+
+    tracing::info!(target: "mcp", tool = "start_sprint", sprint_id = input.sprint_id, "Sprint started");
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "sprint": started
+    })))
+}
+
+/// "Sprint 12" → "Sprint 13"; names without a trailing number get " (continued)"
+fn next_sprint_name(name: &str) -> String {
+    let trimmed = name.trim_end();
+    let digits: String = trimmed.chars().rev().take_while(|c| c.is_ascii_digit()).collect();
+
+    if digits.is_empty() {
+        return format!("{} (continued)", trimmed);
+    }
+
+    let number: String = digits.chars().rev().collect();
+    match number.parse::<u64>() {
+        Ok(n) => format!("{}{}", &trimmed[..trimmed.len() - number.len()], n + 1),
+        Err(_) => format!("{} (continued)", trimmed),
+    }
+}
+
+pub async fn complete_sprint_handler(
+    input: CompleteSprintInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "complete_sprint",
+        sprint_id = input.sprint_id,
+        incomplete_issues = ?input.incomplete_issues,
+        "Completing sprint"
+    );
+
+    let sprint = fetch_sprint("complete_sprint", input.sprint_id, ctx).await?;
+    require_state(&sprint, "active", "completed")?;
+
+    let incomplete: Vec<String> = ctx
+        .client
+        .get_sprint_issues(
+            input.sprint_id,
+            Some("statusCategory != Done"),
+            "status",
+            MAX_SPRINT_ISSUES,
+            &ctx.auth,
+        )
+        .await
+        .mcp_context(
+            error_ctx!("complete_sprint", "get incomplete issues")
+                .with_metadata("sprint_id", input.sprint_id.to_string())
+        )?
+        .into_iter()
+        .map(|i| i.key)
+        .collect();
+
+    let mut destination = json!({ "type": "backlog" });
+
+    if !incomplete.is_empty() {
+        let target_sprint = match input.incomplete_issues {
+            IncompleteIssuesTarget::Backlog => None,
+            IncompleteIssuesTarget::NextSprint | IncompleteIssuesTarget::NewSprint => {
+                let board_id = sprint.origin_board_id.ok_or_else(|| {
+                    rmcp::ErrorData::invalid_params(
+                        "Sprint has no origin board; use incomplete_issues = backlog",
+                        None,
+                    )
+                })?;
+
+                if input.incomplete_issues == IncompleteIssuesTarget::NextSprint {
+                    let future = ctx
+                        .client
+                        .list_sprints(board_id, Some("future"), &ctx.auth)
+                        .await
+                        .mcp_context(
+                            error_ctx!("complete_sprint", "list future sprints")
+                                .with_metadata("board_id", board_id.to_string())
+                        )?;
+
+                    let next = future.into_iter().next().ok_or_else(|| {
+                        rmcp::ErrorData::invalid_params(
+                            format!(
+                                "Board {} has no future sprint; use incomplete_issues = new_sprint or backlog",
+                                board_id
+                            ),
+                            None,
+                        )
+                    })?;
+                    Some(next)
+                } else {
+                    let name = input.new_sprint_name.clone().unwrap_or_else(|| next_sprint_name(&sprint.name));
+                    let created = ctx
+                        .client
+                        .create_sprint(&json!({ "name": name, "originBoardId": board_id }), &ctx.auth)
+                        .await
+                        .mcp_context(
+                            error_ctx!("complete_sprint", "create sprint")
+                                .with_metadata("board_id", board_id.to_string())
+                                .with_suggestions(get_sprint_suggestions)
+                        )?;
+                    Some(created)
+                }
+            }
+        };
+
+        for chunk in incomplete.chunks(MOVE_CHUNK) {
+            let moved = match &target_sprint {
+                Some(target) => ctx.client.move_issues_to_sprint(target.id, chunk, &ctx.auth).await,
+                None => ctx.client.move_issues_to_backlog(chunk, &ctx.auth).await,
+            };
+            moved.mcp_context(
+                error_ctx!("complete_sprint", "move incomplete issues")
+                    .with_metadata("sprint_id", input.sprint_id.to_string())
+            )?;
+        }
+
+        if let Some(target) = target_sprint {
+            destination = json!({
+                "type": if input.incomplete_issues == IncompleteIssuesTarget::NewSprint { "new_sprint" } else { "next_sprint" },
+                "sprint_id": target.id,
+                "sprint_name": target.name
+            });
+        }
+    }
+
+    let closed = ctx
+        .client
+        .update_sprint(input.sprint_id, &json!({ "state": "closed" }), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("complete_sprint", "close sprint")
+                .with_metadata("sprint_id", input.sprint_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+
+    tracing::info!(
+        target: "mcp",
+        tool = "complete_sprint",
+        sprint_id = input.sprint_id,
+        moved = incomplete.len(),
+        "Sprint completed"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "sprint": closed,
+        "incomplete_issues": incomplete,
+        "incomplete_count": incomplete.len(),
+        "moved_to": destination
+    })))
+}
+
+pub async fn delete_sprint_handler(
+    input: DeleteSprintInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "delete_sprint", sprint_id = input.sprint_id, "Deleting sprint");
+
+    let sprint = fetch_sprint("delete_sprint", input.sprint_id, ctx).await?;
+    require_state(&sprint, "future", "deleted")?;
+
+    let issues = ctx
+        .client
+        .get_sprint_issues(input.sprint_id, None, "key", MAX_SPRINT_ISSUES, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("delete_sprint", "get sprint issues")
+                .with_metadata("sprint_id", input.sprint_id.to_string())
+        )?;
+
+    ctx.client
+        .delete_sprint(input.sprint_id, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("delete_sprint", "delete sprint")
+                .with_metadata("sprint_id", input.sprint_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "sprint_id": input.sprint_id,
+        "name": sprint.name,
+        "issues_moved_to_backlog": issues.len()
+    })))
+}
+
+/// Issues fetched for the report, members and ones removed mid-sprint alike
+const MAX_REPORT_ISSUES: usize = 500;
+
+/// One issue's history as far as the sprint is concerned
+struct TrackedIssue {
+    issue: Value,
+    key: String,
+    created: Option<DateTime<Utc>>,
+    done_at: Option<DateTime<Utc>>,
+    membership: Vec<(DateTime<Utc>, bool)>,
+    in_sprint_now: bool,
+}
+
+impl TrackedIssue {
+    fn new(issue: Value, sprint_id: u64, in_sprint_now: bool) -> Self {
+        let fields = issue.get("fields").cloned().unwrap_or(Value::Null);
+        let key = issue.get("key").and_then(|k| k.as_str()).unwrap_or_default().to_string();
+        let created = fields.get("created").and_then(|c| c.as_str()).and_then(parse_jira_datetime);
+
+        let done_at = if status_category_name(&fields) == "Done" {
+            fields
+                .get("resolutiondate")
+                .and_then(|r| r.as_str())
+                .and_then(parse_jira_datetime)
+                .or_else(|| {
+                    changelog_items(&issue, |i| i.get("fieldId").and_then(|f| f.as_str()) == Some("status"))
+                        .last()
+                        .map(|(at, _)| *at)
+                })
+        } else {
+            None
+        };
+
+        let membership = sprint_membership_changes(&issue, sprint_id);
+
+        TrackedIssue { issue, key, created, done_at, membership, in_sprint_now }
+    }
+
+    fn in_sprint_at(&self, at: DateTime<Utc>) -> bool {
+        in_sprint_at(&self.membership, self.created, self.in_sprint_now, at)
+    }
+
+    fn done_by(&self, at: DateTime<Utc>) -> bool {
+        self.done_at.is_some_and(|d| d <= at)
+    }
+
+    /// Story points (or 1 on issue-count boards) at a point in time
+    fn weight_at(&self, estimation_field: Option<&str>, at: DateTime<Utc>) -> f64 {
+        match estimation_field {
+            Some(field) => {
+                let current = self.issue.get("fields").and_then(|f| f.get(field)).and_then(|v| v.as_f64());
+                numeric_value_at(&self.issue, field, current, at).unwrap_or(0.0)
+            }
+            None => 1.0,
+        }
+    }
+
+    /// When the issue joined the sprint after it started, if it did
+    fn added_after(&self, start: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.in_sprint_at(start) {
+            return None;
+        }
+
+        self.membership
+            .iter()
+            .find(|(t, joined)| *joined && *t > start)
+            .map(|(t, _)| *t)
+            .or(self.created.filter(|c| *c > start))
+    }
+
+    fn removed_after(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.in_sprint_at(end) {
+            return None;
+        }
+
+        self.membership
+            .iter()
+            .rev()
+            .find(|(t, joined)| !*joined && *t > start && *t <= end)
+            .map(|(t, _)| *t)
+    }
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+async fn fetch_estimation_field(
+    tool: &'static str,
+    board_id: u64,
+    ctx: &JiraCtx,
+) -> Result<Option<String>, rmcp::ErrorData> {
+    let cfg = ctx
+        .client
+        .get_board_configuration(board_id, &ctx.auth)
+        .await
+        .mcp_context(
+            HandlerErrorContext::new(tool, "get board configuration")
+                .with_metadata("board_id", board_id.to_string())
+        )?;
+
+    Ok(board_estimation_field(&cfg))
+}
+
+/// Start (if started) and end of a sprint; open sprints end now
+fn sprint_window(sprint: &Sprint) -> (Option<DateTime<Utc>>, DateTime<Utc>) {
+    let start = sprint.start_date.as_deref().and_then(parse_jira_datetime);
+    let end = sprint
+        .complete_date
+        .as_deref()
+        .and_then(parse_jira_datetime)
+        .unwrap_or_else(Utc::now);
+
+    (start, end)
+}
+
+/// Which parts of the sprint's issue data hit a fetch limit
+#[derive(Debug, Default)]
+struct SprintTruncation {
+    /// More sprint members than `MAX_REPORT_ISSUES`
+    members: bool,
+    /// More removal candidates than `MAX_REPORT_ISSUES`
+    removed: bool,
+    /// Issues whose full changelog could not be loaded
+    changelogs: Vec<String>,
+}
+
+impl SprintTruncation {
+    fn any(&self) -> bool {
+        self.members || self.removed || !self.changelogs.is_empty()
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "members": self.members,
+            "removed": self.removed,
+            "changelogs": self.changelogs
+        })
+    }
+}
+
+/// Replace changelogs cut short by `expand=changelog` with the full history
+async fn complete_changelogs(tool: &'static str, issues: &mut [Value], truncation: &mut SprintTruncation, ctx: &JiraCtx) {
+    let partial: Vec<(usize, String)> = issues
+        .iter()
+        .enumerate()
+        .filter(|(_, issue)| changelog_is_partial(issue))
+        .filter_map(|(i, issue)| Some((i, issue.get("key")?.as_str()?.to_string())))
+        .collect();
+
+    let lookups: Vec<_> = partial
+        .into_iter()
+        .map(|(index, key)| async move {
+            let histories = ctx.client.get_issue_changelog(&key, &ctx.auth).await;
+            (index, key, histories)
+        })
+        .collect();
+
+    for (index, key, histories) in stream::iter(lookups).buffered(5).collect::<Vec<_>>().await {
+        match histories {
+            Ok(histories) => issues[index]["changelog"]["histories"] = Value::Array(histories),
+            Err(e) => {
+                tracing::warn!(target: "mcp", tool = tool, issue_key = %key, error = %describe_error(&e), "Could not load full changelog");
+                truncation.changelogs.push(key);
+            }
+        }
+    }
+}
+
+/// Issues in the sprint, with their changelogs, plus (when `include_removed` is set) the ones
+/// removed after it started
+async fn track_sprint_issues(
+    tool: &'static str,
+    sprint: &Sprint,
+    estimation_field: Option<&str>,
+    include_removed: bool,
+    ctx: &JiraCtx,
+) -> Result<(Vec<TrackedIssue>, SprintTruncation), rmcp::ErrorData> {
+    let mut fields_param = String::from("summary,status,issuetype,assignee,created,resolutiondate,project");
+    if let Some(field) = estimation_field {
+        fields_param.push(',');
+        fields_param.push_str(field);
+    }
+
+    let members = ctx
+        .client
+        .search_issues(
+            &format!("sprint = {}", sprint.id),
+            Some(&fields_param),
+            Some("changelog"),
+            MAX_REPORT_ISSUES,
+            None,
+            &ctx.auth,
+        )
+        .await
+        .mcp_context(
+            HandlerErrorContext::new(tool, "get sprint issues")
+                .with_metadata("sprint_id", sprint.id.to_string())
+        )?;
+
+    let mut truncation = SprintTruncation { members: !members.is_last, ..Default::default() };
+    let mut member_issues = members.issues;
+    complete_changelogs(tool, &mut member_issues, &mut truncation, ctx).await;
+
+    let mut tracked: Vec<TrackedIssue> = member_issues
+        .into_iter()
+        .map(|i| TrackedIssue::new(i, sprint.id, true))
+        .collect();
+
+    let (Some(start), _) = sprint_window(sprint) else {
+        return Ok((tracked, truncation));
+    };
+    if !include_removed {
+        return Ok((tracked, truncation));
+    }
+
+    // Issues taken out of the sprint no longer match `sprint = id`; find them through their changelog
+    let projects: BTreeSet<String> = tracked
+        .iter()
+        .filter_map(|t| {
+            t.issue
+                .get("fields")
+                .and_then(|f| f.get("project"))
+                .and_then(|p| p.get("key"))
+                .and_then(|k| k.as_str())
+                .map(|k| k.to_string())
+        })
+        .collect();
+
+    if projects.is_empty() {
+        return Ok((tracked, truncation));
+    }
+
+    let jql = format!(
+        "project in ({}) AND updated >= \"{}\" AND (sprint is EMPTY OR sprint != {})",
+        projects.iter().map(|p| quote_jql_value(p)).collect::<Vec<_>>().join(", "),
+        start.format("%Y-%m-%d %H:%M"),
+        sprint.id
+    );
+
+    match ctx
+        .client
+        .search_issues(&jql, Some(&fields_param), Some("changelog"), MAX_REPORT_ISSUES, None, &ctx.auth)
+        .await
+    {
+        Ok(page) => {
+            truncation.removed = !page.is_last;
+
+            // Issues without a sprint change cannot have left this sprint; partial histories are loaded in full first
+            let mut candidates: Vec<Value> = page
+                .issues
+                .into_iter()
+                .filter(|i| !sprint_membership_changes(i, sprint.id).is_empty() || changelog_is_partial(i))
+                .collect();
+            complete_changelogs(tool, &mut candidates, &mut truncation, ctx).await;
+
+            tracked.extend(
+                candidates
+                    .into_iter()
+                    .map(|i| TrackedIssue::new(i, sprint.id, false))
+                    .filter(|t| !t.membership.is_empty()),
+            );
+        }
+        Err(e) => tracing::warn!(
+            target: "mcp",
+            tool = tool,
+            error = %describe_error(&e),
+            "Could not look up issues removed from the sprint"
+        ),
+    }
+
+    Ok((tracked, truncation))
+}
+
+fn changelog_is_partial(issue: &Value) -> bool {
+    let changelog = issue.get("changelog");
+    let total = changelog.and_then(|c| c.get("total")).and_then(|t| t.as_u64()).unwrap_or(0);
+    let returned = changelog
+        .and_then(|c| c.get("histories"))
+        .and_then(|h| h.as_array())
+        .map_or(0, |h| h.len() as u64);
+    total > returned
+}
+
+/// Estimate committed at sprint start and completed by its end
+fn committed_and_completed(tracked: &[TrackedIssue], field: Option<&str>, start: DateTime<Utc>, end: DateTime<Utc>) -> (f64, f64) {
+    let committed = tracked
+        .iter()
+        .filter(|t| t.in_sprint_at(start))
+        .map(|t| t.weight_at(field, start))
+        .sum();
+    let completed = tracked
+        .iter()
+        .filter(|t| t.in_sprint_now && t.done_by(end))
+        .map(|t| t.weight_at(field, end))
+        .sum();
+
+    (committed, completed)
+}
+
+pub async fn sprint_report_handler(
+    input: SprintReportInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "sprint_report", sprint_id = input.sprint_id, "Building sprint report");
+
+    let sprint = fetch_sprint("sprint_report", input.sprint_id, ctx).await?;
+    let board_id = input.board_id.or(sprint.origin_board_id);
+
+    let estimation_field = match board_id {
+        Some(board_id) => fetch_estimation_field("sprint_report", board_id, ctx).await?,
+        None => None,
+    };
+
+    let (start, end) = sprint_window(&sprint);
+    let (tracked, truncation) = track_sprint_issues("sprint_report", &sprint, estimation_field.as_deref(), true, ctx).await?;
+
+    let field = estimation_field.as_deref();
+    let mut by_category: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let (mut committed, mut committed_count) = (0.0, 0usize);
+    let (mut completed, mut completed_count) = (0.0, 0usize);
+    let (mut remaining, mut remaining_count) = (0.0, 0usize);
+
+    for t in &tracked {
+        let fields = t.issue.get("fields").cloned().unwrap_or(Value::Null);
+
+        if let Some(start) = start {
+            if t.in_sprint_at(start) {
+                committed += t.weight_at(field, start);
+                committed_count += 1;
+            }
+
+            if let Some(at) = t.added_after(start).filter(|at| *at <= end) {
+                added.push(json!({ "key": t.key, "at": format_jira_datetime(at), "estimate": t.weight_at(field, at) }));
+            }
+
+            if let Some(at) = t.removed_after(start, end) {
+                removed.push(json!({ "key": t.key, "at": format_jira_datetime(at), "estimate": t.weight_at(field, at) }));
+            }
+        }
+
+        if !t.in_sprint_now {
+            continue;
+        }
+
+        let weight = t.weight_at(field, end);
+        if t.done_by(end) {
+            completed += weight;
+            completed_count += 1;
+        } else {
+            remaining += weight;
+            remaining_count += 1;
+        }
+
+        by_category.entry(status_category_name(&fields)).or_default().push(json!({
+            "key": t.key,
+            "summary": fields.get("summary"),
+            "status": fields.get("status").and_then(|s| s.get("name")),
+            "issue_type": fields.get("issuetype").and_then(|s| s.get("name")),
+            "assignee": fields.get("assignee").and_then(|a| a.get("displayName")),
+            "estimate": field.and_then(|f| fields.get(f)).cloned(),
+            "added_during_sprint": start.is_some_and(|s| t.added_after(s).is_some())
+        }));
+    }
+
+    let burndown = match start {
+        Some(start) => burndown_series(&tracked, field, start, end, &sprint, committed),
+        None => Vec::new(),
+    };
+
+    let unit = if field.is_some() { "points" } else { "issues" };
+    let added_total: f64 = added.iter().filter_map(|a| a.get("estimate").and_then(|e| e.as_f64())).sum();
+    let removed_total: f64 = removed.iter().filter_map(|a| a.get("estimate").and_then(|e| e.as_f64())).sum();
+
+    tracing::info!(
+        target: "mcp",
+        tool = "sprint_report",
+        sprint_id = input.sprint_id,
+        issues = tracked.len(),
+        "Sprint report built"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "sprint": sprint,
+        "board_id": board_id,
+        "estimation_field": estimation_field,
+        "unit": unit,
+        "totals": {
+            "committed": round2(committed),
+            "committed_issues": committed_count,
+            "completed": round2(completed),
+            "completed_issues": completed_count,
+            "remaining": round2(remaining),
+            "remaining_issues": remaining_count,
+            "added": round2(added_total),
+            "added_issues": added.len(),
+            "removed": round2(removed_total),
+            "removed_issues": removed.len()
+        },
+        "issues_by_category": by_category,
+        "scope_changes": {
+            "added": added,
+            "removed": removed
+        },
+        "burndown": burndown,
+        "truncated": truncation.any(),
+        "truncation": truncation.to_json()
+    })))
+}
+
+/// Remaining work at the end of each sprint day, with an ideal line from the committed total to zero
+fn burndown_series(
+    tracked: &[TrackedIssue],
+    field: Option<&str>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    sprint: &Sprint,
+    committed: f64,
+) -> Vec<Value> {
+    let planned_end = sprint.end_date.as_deref().and_then(parse_jira_datetime).unwrap_or(end);
+    let planned_days = (planned_end.date_naive() - start.date_naive()).num_days().max(1) as f64;
+
+    let mut series = Vec::new();
+    let mut day = start.date_naive();
+    let last_day = end.date_naive();
+
+    while day <= last_day {
+        let at = day
+            .and_hms_opt(23, 59, 59)
+            .map(|d| d.and_utc())
+            .unwrap_or(end)
+            .min(end);
+        let elapsed = (day - start.date_naive()).num_days() as f64;
+
+        let remaining: f64 = tracked
+            .iter()
+            .filter(|t| t.in_sprint_at(at) && !t.done_by(at))
+            .map(|t| t.weight_at(field, at))
+            .sum();
+
+        series.push(json!({
+            "date": day.format("%Y-%m-%d").to_string(),
+            "remaining": round2(remaining),
+            "ideal": round2((committed * (1.0 - elapsed / planned_days)).max(0.0))
+        }));
+
+        day += Duration::days(1);
+    }
+
+    series
+}
+
+/// Committed ("estimated") and completed values of a sprint in the Greenhopper velocity chart
+fn velocity_chart_entry(chart: &Value, sprint_id: u64) -> Option<(f64, f64)> {
+    let entry = chart.get("velocityStatEntries")?.get(sprint_id.to_string())?;
+    let value = |key: &str| entry.get(key).and_then(|e| e.get("value")).and_then(|v| v.as_f64());
+
+    Some((value("estimated")?, value("completed")?))
+}
+
+/// Least-squares slope of the values per sprint
+fn trend_slope(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = values.iter().sum::<f64>() / n;
+    let (num, den) = values.iter().enumerate().fold((0.0, 0.0), |(num, den), (i, y)| {
+        let dx = i as f64 - mean_x;
+        (num + dx * (y - mean_y), den + dx * dx)
+    });
+
+    if den == 0.0 { 0.0 } else { num / den }
+}
+
+pub async fn board_velocity_handler(
+    input: BoardVelocityInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "board_velocity",
+        board_id = input.board_id,
+        sprints = input.sprints,
+        "Computing board velocity"
+    );
+
+    if input.sprints == 0 {
+        return Err(rmcp::ErrorData::invalid_params("sprints must be at least 1", None));
+    }
+
+    let mut closed = ctx
+        .client
+        .list_sprints(input.board_id, Some("closed"), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("board_velocity", "list closed sprints")
+                .with_metadata("board_id", input.board_id.to_string())
+                .with_suggestions(get_sprint_suggestions)
+        )?;
+
+    closed.sort_by_key(|s| {
+        s.complete_date
+            .as_deref()
+            .or(s.end_date.as_deref())
+            .and_then(parse_jira_datetime)
+    });
+    let skip = closed.len().saturating_sub(input.sprints);
+    let closed: Vec<Sprint> = closed.into_iter().skip(skip).collect();
+
+    if closed.is_empty() {
+        return Ok(CallToolResult::structured(json!({
+            "board_id": input.board_id,
+            "sprints": [],
+            "message": "Board has no closed sprints"
+        })));
+    }
+
+    let chart = match ctx.client.get_velocity_chart(input.board_id, &ctx.auth).await {
+        Ok(chart) => Some(chart),
+        Err(e) => {
+            tracing::warn!(
+                target: "mcp",
+                tool = "board_velocity",
+                error = %describe_error(&e),
+                "Velocity chart unavailable, computing from sprint issues"
+            );
+            None
+        }
+    };
+
+    let needs_fallback = closed
+        .iter()
+        .any(|s| chart.as_ref().and_then(|c| velocity_chart_entry(c, s.id)).is_none());
+    let estimation_field = if needs_fallback {
+        fetch_estimation_field("board_velocity", input.board_id, ctx).await?
+    } else {
+        None
+    };
+
+    let mut rows = Vec::new();
+    let mut completed_values = Vec::new();
+    let (mut committed_total, mut completed_total) = (0.0, 0.0);
+
+    for sprint in &closed {
+        let (committed, completed, source) = match chart.as_ref().and_then(|c| velocity_chart_entry(c, sprint.id)) {
+            Some((committed, completed)) => (committed, completed, "velocity_chart"),
+            None => {
+                let (start, end) = sprint_window(sprint);
+                let Some(start) = start else {
+                    continue;
+                };
+                // The removed-issue scan searches whole projects; per sprint it is too costly here,
+                // so committed only counts issues still in the sprint
+                let (tracked, _) =
+                    track_sprint_issues("board_velocity", sprint, estimation_field.as_deref(), false, ctx).await?;
+                let (committed, completed) =
+                    committed_and_completed(&tracked, estimation_field.as_deref(), start, end);
+                (committed, completed, "computed")
+            }
+        };
+
+        committed_total += committed;
+        completed_total += completed;
+        completed_values.push(completed);
+
+        rows.push(json!({
+            "sprint_id": sprint.id,
+            "name": sprint.name,
+            "start_date": sprint.start_date,
+            "complete_date": sprint.complete_date,
+            "committed": round2(committed),
+            "completed": round2(completed),
+            "completion_rate": (committed > 0.0).then(|| round2(completed / committed)),
+            "source": source
+        }));
+    }
+
+    let count = completed_values.len().max(1) as f64;
+    let average_completed = completed_total / count;
+    let recent: Vec<f64> = completed_values.iter().rev().take(3).copied().collect();
+    let recent_average = recent.iter().sum::<f64>() / recent.len().max(1) as f64;
+    let slope = trend_slope(&completed_values);
+    let direction = if slope.abs() < average_completed * 0.05 {
+        "flat"
+    } else if slope > 0.0 {
+        "up"
+    } else {
+        "down"
+    };
+
+    Ok(CallToolResult::structured(json!({
+        "board_id": input.board_id,
+        "estimation_field": estimation_field,
+        "sprints": rows,
+        "average_committed": round2(committed_total / count),
+        "average_completed": round2(average_completed),
+        "recent_average_completed": round2(recent_average),
+        "completion_rate": (committed_total > 0.0).then(|| round2(completed_total / committed_total)),
+        "trend": {
+            "slope_per_sprint": round2(slope),
+            "direction": direction
+        }
+    })))
+}
@@ -0,0 +1,220 @@
+use anyhow::Result;
+use jira_client::utils::markdown_to_adf;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::{describe_error, log_err, ResultMcpExt};
+use crate::models::{CreateFromTemplateInput, CreateIssueInput, CreateIssueResult};
+use crate::services::CoreService;
+use crate::settings::default_settings_path;
+use crate::utils::{
+    empty_sections, fields_from_createmeta, fill_template, process_jira_fields, template_placeholders,
+};
+
+/// Required fields Jira fills in itself when they are left out
+const IMPLICIT_FIELDS: &[&str] = &["project", "issuetype", "reporter"];
+
+pub async fn list_templates_handler(ctx: &JiraCtx) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "list_templates");
+
+    let templates: Vec<Value> = ctx
+        .settings
+        .templates
+        .iter()
+        .map(|(name, t)| {
+            let mut placeholders = template_placeholders(t.summary.as_deref().unwrap_or(""));
+            for p in template_placeholders(&t.description) {
+                if !placeholders.contains(&p) {
+                    placeholders.push(p);
+                }
+            }
+
+            json!({
+                "name": name,
+                "about": t.about,
+                "issue_type": t.issue_type,
+                "project": t.project,
+                "summary": t.summary,
+                "labels": t.labels,
+                "components": t.components,
+                "priority": t.priority,
+                "placeholders": placeholders,
+                "required_sections": t.required_sections
+            })
+        })
+        .collect();
+
+    let mut response = json!({
+        "templates": templates,
+        "count": templates.len()
+    });
+
+    if templates.is_empty() {
+        response["hint"] = json!(format!(
+            "No templates configured. Add [templates.<name>] tables to {}",
+            default_settings_path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "config.toml".to_string())
+        ));
+    }
+
+    Ok(CallToolResult::structured(response))
+}
+
+pub async fn create_from_template_handler(
+    input: CreateFromTemplateInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "create_from_template",
+        template = %input.template,
+        project = ?input.project,
+        "Creating issue from template"
+    );
+
+    let template = ctx
+        .settings
+        .templates
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(&input.template))
+        .map(|(_, t)| t)
+        .ok_or_else(|| {
+            let names: Vec<&str> = ctx.settings.templates.keys().map(|k| k.as_str()).collect();
+            rmcp::ErrorData::invalid_params(
+                format!("Unknown template '{}'. Available: {}", input.template, names.join(", ")),
+                None,
+            )
+        })?;
+
+    let project = input
+        .project
+        .clone()
+        .or_else(|| template.project.clone())
+        .ok_or_else(|| {
+            rmcp::ErrorData::invalid_params(
+                format!("Template '{}' has no default project; pass project", input.template),
+                None,
+            )
+        })?;
+
+    let (summary, mut missing) = match (&input.summary, &template.summary) {
+        (Some(summary), _) => (summary.clone(), Vec::new()),
+        (None, Some(pattern)) => fill_template(pattern, &input.values),
+        (None, None) => {
+            return Err(rmcp::ErrorData::invalid_params(
+                format!("Template '{}' has no summary pattern; pass summary", input.template),
+                None,
+            ));
+        }
+    };
+
+    let (description, missing_in_body) = fill_template(&template.description, &input.values);
+    for name in missing_in_body {
+        if !missing.contains(&name) {
+            missing.push(name);
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("Missing values for placeholders: {}", missing.join(", ")),
+            Some(json!({ "missing_placeholders": missing })),
+        ));
+    }
+
+    let empty = empty_sections(&description, &template.required_sections);
+    if !empty.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("Required sections are empty: {}", empty.join(", ")),
+            Some(json!({ "empty_sections": empty })),
+        ));
+    }
+
+    let mut fields = serde_json::Map::new();
+    fields.insert("project".into(), json!({ "key": project }));
+    fields.insert("issuetype".into(), json!({ "name": template.issue_type }));
+    fields.insert("summary".into(), json!(summary));
+
+    if !description.trim().is_empty() {
+        fields.insert("description".into(), markdown_to_adf(&description));
+    }
+
+    if !template.labels.is_empty() {
+        fields.insert("labels".into(), json!(template.labels));
+    }
+
+    if !template.components.is_empty() {
+        let components: Vec<Value> = template.components.iter().map(|c| json!({ "name": c })).collect();
+        fields.insert("components".into(), Value::Array(components));
+    }
+
+    if let Some(priority) = &template.priority {
+        fields.insert("priority".into(), json!({ "name": priority }));
+    }
+
+    if let Some(extra) = &input.fields {
+        fields.extend(process_jira_fields(extra));
+    }
+
+    let createmeta = ctx
+        .client
+        .get_createmeta(Some(&project), Some(&template.issue_type), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_from_template", "get createmeta")
+                .with_metadata("project", project.clone())
+                .with_metadata("issue_type", template.issue_type.clone())
+        )?;
+
+    let defs = fields_from_createmeta(&createmeta, Some(&project), Some(&template.issue_type));
+    if defs.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!(
+                "Issue type '{}' is not available in project {}",
+                template.issue_type, project
+            ),
+            None,
+        ));
+    }
+
+    let missing_fields: Vec<Value> = defs
+        .iter()
+        .filter(|d| d.required && !IMPLICIT_FIELDS.contains(&d.id.as_str()) && !fields.contains_key(&d.id))
+        .map(|d| json!({ "id": d.id, "name": d.name, "allowed_values": d.allowed_values }))
+        .collect();
+
+    if !missing_fields.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Required fields are missing; pass them in fields",
+            Some(json!({ "missing_fields": missing_fields })),
+        ));
+    }
+
+    let svc = CoreService::new(ctx);
+    let CreateIssueResult::Created(created) = svc
+        .create_issue(CreateIssueInput {
+            fields: Value::Object(fields),
+            check_duplicates: false,
+        })
+        .await
+        .map_err(|e| log_err("create_from_template", "create_failed", describe_error(&e)))?;
+
+    tracing::info!(
+        target: "mcp",
+        tool = "create_from_template",
+        template = %input.template,
+        issue_key = %created.issue_key,
+        "Issue created from template"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "issue_key": created.issue_key,
+        "url": created.url,
+        "template": input.template,
+        "project": project,
+        "issue_type": template.issue_type
+    })))
+}
@@ -0,0 +1,682 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, Utc};
+use futures::stream::{self, StreamExt};
+use jira_client::utils::markdown_to_adf;
+use rmcp::model::CallToolResult;
+use serde_json::{json, Map, Value};
+
+use crate::context::JiraCtx;
+use crate::error_ctx;
+use crate::errors::suggestions::get_version_suggestions;
+use crate::errors::{describe_error, HandlerErrorContext, ResultMcpExt};
+use crate::handlers::error_utils::get_jql_suggestions;
+use crate::handlers::issues::description_text;
+use crate::models::{
+    ArchiveVersionInput, CreateVersionInput, GenerateReleaseNotesInput, ListVersionsInput, ReleaseNotesFormat,
+    ReleaseNotesGrouping, ReleaseNotesTarget, ReleaseVersionInput, UpdateVersionInput,
+};
+use crate::utils::{
+    excerpt, render_release_notes_html, render_release_notes_markdown, ReleaseNoteItem, ReleaseNotesGroup,
+};
+
+const VERSION_COUNT_CONCURRENCY: usize = 5;
+/// Most issues included in generated release notes
+const MAX_RELEASE_NOTE_ISSUES: usize = 500;
+/// Longest description Jira stores on a version
+const MAX_VERSION_DESCRIPTION_CHARS: usize = 16_384;
+
+pub(crate) fn version_summary(version: &Value) -> Value {
+    json!({
+        "id": version.get("id"),
+        "name": version.get("name"),
+        "description": version.get("description"),
+        "released": version.get("released").and_then(|r| r.as_bool()).unwrap_or(false),
+        "archived": version.get("archived").and_then(|a| a.as_bool()).unwrap_or(false),
+        "overdue": version.get("overdue"),
+        "start_date": version.get("startDate"),
+        "release_date": version.get("releaseDate"),
+        "issue_status": version.get("issuesStatusForFixVersion")
+    })
+}
+
+/// Validate a YYYY-MM-DD date parameter
+fn version_date(param: &str, value: &str) -> Result<String, rmcp::ErrorData> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .map_err(|_| {
+            rmcp::ErrorData::invalid_params(
+                format!("{} must be a date as YYYY-MM-DD, got '{}'", param, value),
+                None,
+            )
+        })
+}
+
+fn version_payload(
+    name: Option<&str>,
+    description: Option<&str>,
+    start_date: Option<&str>,
+    release_date: Option<&str>,
+) -> Result<Map<String, Value>, rmcp::ErrorData> {
+    let mut payload = Map::new();
+    if let Some(name) = name {
+        payload.insert("name".into(), json!(name.trim()));
+    }
+    if let Some(description) = description {
+        payload.insert("description".into(), json!(description));
+    }
+    if let Some(start) = start_date {
+        payload.insert("startDate".into(), json!(version_date("start_date", start)?));
+    }
+    if let Some(release) = release_date {
+        payload.insert("releaseDate".into(), json!(version_date("release_date", release)?));
+    }
+    Ok(payload)
+}
+
+pub async fn list_versions_handler(
+    input: ListVersionsInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "list_versions",
+        project_key = %input.project_key,
+        status = ?input.status
+    );
+
+    let versions = ctx
+        .client
+        .list_versions(&input.project_key, input.status.map(|s| s.as_str()), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("list_versions", "list versions")
+                .with_metadata("project_key", input.project_key.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    let mut out: Vec<Value> = versions.iter().map(version_summary).collect();
+
+    if input.include_counts {
+        let lookups: Vec<_> = versions
+            .iter()
+            .map(|v| {
+                let id = v.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+                async move {
+                    match ctx.client.get_version_unresolved_count(&id, &ctx.auth).await {
+                        Ok(counts) => Some(counts),
+                        Err(e) => {
+                            tracing::warn!(
+                                target: "mcp",
+                                tool = "list_versions",
+                                version_id = %id,
+                                error = %describe_error(&e),
+                                "Could not get issue counts"
+                            );
+                            None
+                        }
+                    }
+                }
+            })
+            .collect();
+        let counts: Vec<Option<Value>> = stream::iter(lookups).buffered(VERSION_COUNT_CONCURRENCY).collect().await;
+
+        for (version, counts) in out.iter_mut().zip(counts) {
+            if let Some(counts) = counts {
+                version["issues_count"] = counts.get("issuesCount").cloned().unwrap_or(Value::Null);
+                version["unresolved_count"] = counts.get("issuesUnresolvedCount").cloned().unwrap_or(Value::Null);
+            }
+        }
+    }
+
+    Ok(CallToolResult::structured(json!({
+        "project_key": input.project_key,
+        "versions": out,
+        "count": out.len()
+    })))
+}
+
+pub async fn create_version_handler(
+    input: CreateVersionInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "create_version",
+        project_key = %input.project_key,
+        name = %input.name,
+        "Creating version"
+    );
+
+    if input.name.trim().is_empty() {
+        return Err(rmcp::ErrorData::invalid_params("Version name must not be empty", None));
+    }
+
+    let mut payload = version_payload(
+        Some(&input.name),
+        input.description.as_deref(),
+        input.start_date.as_deref(),
+        input.release_date.as_deref(),
+    )?;
+
+    let project = ctx
+        .client
+        .get_project(&input.project_key, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_version", "get project")
+                .with_metadata("project_key", input.project_key.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+    let project_id = project
+        .get("id")
+        .and_then(|id| id.as_str())
+        .and_then(|id| id.parse::<u64>().ok())
+        .ok_or_else(|| rmcp::ErrorData::internal_error("Project response has no id", None))?;
+    payload.insert("projectId".into(), json!(project_id));
+
+    let created = ctx
+        .client
+        .create_version(&Value::Object(payload), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("create_version", "create version")
+                .with_metadata("project_key", input.project_key.clone())
+                .with_metadata("name", input.name.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "version": version_summary(&created)
+    })))
+}
+
+pub async fn update_version_handler(
+    input: UpdateVersionInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "update_version", version_id = %input.version_id);
+
+    let payload = version_payload(
+        input.name.as_deref(),
+        input.description.as_deref(),
+        input.start_date.as_deref(),
+        input.release_date.as_deref(),
+    )?;
+    if payload.is_empty() {
+        return Err(rmcp::ErrorData::invalid_params(
+            "Nothing to update: pass name, description, start_date or release_date",
+            None,
+        ));
+    }
+    let updated_fields: Vec<String> = payload.keys().cloned().collect();
+
+    let updated = ctx
+        .client
+        .update_version(&input.version_id, &Value::Object(payload), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("update_version", "update version")
+                .with_metadata("version_id", input.version_id.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "version": version_summary(&updated),
+        "updated_fields": updated_fields
+    })))
+}
+
+pub async fn release_version_handler(
+    input: ReleaseVersionInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "release_version",
+        version_id = %input.version_id,
+        move_unresolved_to = ?input.move_unresolved_to,
+        "Releasing version"
+    );
+
+    let release_date = match &input.release_date {
+        Some(date) => version_date("release_date", date)?,
+        None => Utc::now().date_naive().format("%Y-%m-%d").to_string(),
+    };
+
+    let version = ctx
+        .client
+        .get_version(&input.version_id, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("release_version", "get version")
+                .with_metadata("version_id", input.version_id.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    if version.get("released").and_then(|r| r.as_bool()).unwrap_or(false) {
+        return Err(rmcp::ErrorData::invalid_params(
+            format!("Version {} is already released", input.version_id),
+            Some(json!({ "version": version_summary(&version) })),
+        ));
+    }
+
+    let project_id = match version.get("projectId") {
+        Some(Value::String(id)) => id.clone(),
+        Some(id) => id.to_string(),
+        None => String::new(),
+    };
+
+    let target = match input.move_unresolved_to.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        Some(target) => {
+            let versions = ctx
+                .client
+                .list_versions(&project_id, None, &ctx.auth)
+                .await
+                .mcp_context(
+                    error_ctx!("release_version", "list versions")
+                        .with_metadata("project_id", project_id.clone())
+                )?;
+            let found = versions.into_iter().find(|v| {
+                v.get("id").and_then(|id| id.as_str()) == Some(target)
+                    || v.get("name").and_then(|n| n.as_str()).is_some_and(|n| n.eq_ignore_ascii_case(target))
+            });
+
+            match found {
+                Some(v) if v.get("id").and_then(|id| id.as_str()) == Some(input.version_id.as_str()) => {
+                    return Err(rmcp::ErrorData::invalid_params(
+                        "move_unresolved_to must be a different version",
+                        None,
+                    ));
+                }
+                Some(v) if v.get("archived").and_then(|a| a.as_bool()).unwrap_or(false) => {
+                    return Err(rmcp::ErrorData::invalid_params(
+                        format!("Version '{}' is archived", target),
+                        None,
+                    ));
+                }
+                Some(v) => Some(v),
+                None => {
+                    return Err(rmcp::ErrorData::invalid_params(
+                        format!("No version '{}' in the version's project", target),
+                        Some(json!({ "hint": "Use list_versions to find versions" })),
+                    ));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let unresolved = match ctx.client.get_version_unresolved_count(&input.version_id, &ctx.auth).await {
+        Ok(counts) => counts.get("issuesUnresolvedCount").and_then(|c| c.as_u64()),
+        Err(e) => {
+            tracing::warn!(
+                target: "mcp",
+                tool = "release_version",
+                version_id = %input.version_id,
+                error = %describe_error(&e),
+                "Could not count unresolved issues"
+            );
+            None
+        }
+    };
+
+    let mut warnings: Vec<String> = Vec::new();
+    let mut payload = json!({ "released": true, "releaseDate": release_date });
+
+    // Jira moves the unresolved issues itself as part of the release
+    match &target {
+        Some(target) => {
+            let target_url = target.get("self").and_then(|s| s.as_str()).ok_or_else(|| {
+                rmcp::ErrorData::internal_error("Target version response has no self link", None)
+            })?;
+            payload["moveUnfixedIssuesTo"] = json!(target_url);
+        }
+        None => {
+            if let Some(count) = unresolved.filter(|c| *c > 0) {
+                warnings.push(format!("{} unresolved issue(s) stay on the released version", count));
+            }
+        }
+    }
+
+    let released = ctx
+        .client
+        .update_version(&input.version_id, &payload, &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("release_version", "release version")
+                .with_metadata("version_id", input.version_id.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    tracing::info!(
+        target: "mcp",
+        tool = "release_version",
+        version_id = %input.version_id,
+        unresolved = ?unresolved,
+        "Version released"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "released": true,
+        "version": version_summary(&released),
+        "moved_to": target.as_ref().map(version_summary),
+        "moved_issue_count": target.as_ref().and(unresolved),
+        "warnings": warnings
+    })))
+}
+
+pub async fn archive_version_handler(
+    input: ArchiveVersionInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(target: "mcp", tool = "archive_version", version_id = %input.version_id);
+
+    let archived = ctx
+        .client
+        .update_version(&input.version_id, &json!({ "archived": true }), &ctx.auth)
+        .await
+        .mcp_context(
+            error_ctx!("archive_version", "archive version")
+                .with_metadata("version_id", input.version_id.clone())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    Ok(CallToolResult::structured(json!({
+        "success": true,
+        "version": version_summary(&archived)
+    })))
+}
+
+/// Find a version by id, or by name within a project
+async fn resolve_version(
+    tool: &'static str,
+    version: &str,
+    project_key: Option<&str>,
+    ctx: &JiraCtx,
+) -> Result<Value, rmcp::ErrorData> {
+    let version = version.trim();
+
+    if version.chars().all(|c| c.is_ascii_digit()) {
+        return ctx.client.get_version(version, &ctx.auth).await.mcp_context(
+            HandlerErrorContext::new(tool, "get version")
+                .with_metadata("version_id", version.to_string())
+                .with_suggestions(get_version_suggestions)
+        );
+    }
+
+    let Some(project_key) = project_key else {
+        return Err(rmcp::ErrorData::invalid_params(
+            "A version name needs project_key; pass a version id instead to skip the lookup",
+            None,
+        ));
+    };
+
+    let versions = ctx
+        .client
+        .list_versions(project_key, None, &ctx.auth)
+        .await
+        .mcp_context(
+            HandlerErrorContext::new(tool, "list versions")
+                .with_metadata("project_key", project_key.to_string())
+                .with_suggestions(get_version_suggestions)
+        )?;
+
+    versions
+        .iter()
+        .find(|v| v.get("name").and_then(|n| n.as_str()).is_some_and(|n| n.eq_ignore_ascii_case(version)))
+        .cloned()
+        .ok_or_else(|| {
+            rmcp::ErrorData::invalid_params(
+                format!("No version '{}' in project {}", version, project_key),
+                Some(json!({
+                    "available": versions
+                        .iter()
+                        .filter_map(|v| v.get("name").and_then(|n| n.as_str()))
+                        .collect::<Vec<_>>()
+                })),
+            )
+        })
+}
+
+fn names_in(fields: &Value, key: &str) -> Vec<String> {
+    fields
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().or_else(|| v.get("name").and_then(|n| n.as_str())))
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub async fn generate_release_notes_handler(
+    input: GenerateReleaseNotesInput,
+    ctx: &JiraCtx,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    tracing::info!(
+        target: "mcp",
+        tool = "generate_release_notes",
+        version = ?input.version,
+        jql = ?input.jql,
+        group_by = ?input.group_by,
+        format = ?input.format,
+        post_to = ?input.post_to,
+        "Generating release notes"
+    );
+
+    let (version, jql) = match (&input.version, &input.jql) {
+        (Some(version), None) => {
+            let version = resolve_version("generate_release_notes", version, input.project_key.as_deref(), ctx).await?;
+            let id = version.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+            (Some((id.clone(), version)), format!("fixVersion = {} ORDER BY issuetype ASC, key ASC", id))
+        }
+        (None, Some(jql)) => (None, jql.clone()),
+        _ => {
+            return Err(rmcp::ErrorData::invalid_params(
+                "Provide exactly one of 'version' or 'jql'",
+                None,
+            ));
+        }
+    };
+
+    match input.post_to {
+        Some(ReleaseNotesTarget::Comment) if input.comment_issue_key.is_none() => {
+            return Err(rmcp::ErrorData::invalid_params(
+                "post_to 'comment' needs comment_issue_key",
+                None,
+            ));
+        }
+        Some(ReleaseNotesTarget::VersionDescription) if version.is_none() => {
+            return Err(rmcp::ErrorData::invalid_params(
+                "post_to 'version_description' needs a version",
+                None,
+            ));
+        }
+        _ => {}
+    }
+
+    let mut fields = "summary,issuetype,status,priority,assignee,components,labels,project".to_string();
+    if input.include_descriptions {
+        fields.push_str(",description");
+    }
+
+    let page = ctx
+        .client
+        .search_issues(&jql, Some(&fields), None, MAX_RELEASE_NOTE_ISSUES, None, &ctx.auth)
+        .await
+        .mcp_context({
+            let jql = jql.clone();
+            HandlerErrorContext::new("generate_release_notes", "search issues")
+                .with_metadata("jql", jql.clone())
+                .with_suggestions(move |code| get_jql_suggestions(&jql, code))
+        })?;
+
+    let base_url = ctx.client.base_url();
+    let mut groups: BTreeMap<String, Vec<ReleaseNoteItem>> = BTreeMap::new();
+
+    for issue in &page.issues {
+        let Some(key) = issue.get("key").and_then(|k| k.as_str()) else {
+            continue;
+        };
+        let f = issue.get("fields").cloned().unwrap_or_default();
+        let text = |v: Option<&Value>| v.and_then(|x| x.as_str()).unwrap_or_default().to_string();
+        let name_of = |field: &str| text(f.get(field).and_then(|x| x.get("name")));
+
+        let components = names_in(&f, "components");
+        let labels = names_in(&f, "labels");
+
+        let values = BTreeMap::from([
+            ("key".to_string(), key.to_string()),
+            ("summary".to_string(), text(f.get("summary"))),
+            ("type".to_string(), name_of("issuetype")),
+            ("status".to_string(), name_of("status")),
+            ("priority".to_string(), name_of("priority")),
+            ("assignee".to_string(), text(f.get("assignee").and_then(|a| a.get("displayName")))),
+            ("components".to_string(), components.join(", ")),
+            ("labels".to_string(), labels.join(", ")),
+            (
+                "url".to_string(),
+                base_url.join(&format!("/browse/{}", key)).map(|u| u.to_string()).unwrap_or_default(),
+            ),
+        ]);
+
+        let item = ReleaseNoteItem {
+            values,
+            excerpt: input
+                .include_descriptions
+                .then(|| excerpt(&description_text(f.get("description")), input.excerpt_length))
+                .flatten(),
+        };
+
+        let group_names = match input.group_by {
+            ReleaseNotesGrouping::IssueType => vec![name_of("issuetype")],
+            ReleaseNotesGrouping::Component if components.is_empty() => vec!["No component".to_string()],
+            ReleaseNotesGrouping::Component => components,
+            ReleaseNotesGrouping::Label if labels.is_empty() => vec!["No label".to_string()],
+            ReleaseNotesGrouping::Label => labels,
+            ReleaseNotesGrouping::None => vec![String::new()],
+        };
+        for name in group_names {
+            groups.entry(name).or_default().push(item.clone());
+        }
+    }
+
+    let groups: Vec<ReleaseNotesGroup> = groups
+        .into_iter()
+        .map(|(name, items)| ReleaseNotesGroup { name, items })
+        .collect();
+
+    let today = Utc::now().date_naive().format("%Y-%m-%d").to_string();
+    let project = input.project_key.clone().unwrap_or_else(|| {
+        page.issues
+            .first()
+            .and_then(|i| i.pointer("/fields/project/key"))
+            .and_then(|k| k.as_str())
+            .unwrap_or_default()
+            .to_string()
+    });
+    let version_name = version
+        .as_ref()
+        .and_then(|(_, v)| v.get("name").and_then(|n| n.as_str()))
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| project.clone());
+    let values = BTreeMap::from([
+        ("version".to_string(), version_name),
+        ("project".to_string(), project),
+        (
+            "release_date".to_string(),
+            version
+                .as_ref()
+                .and_then(|(_, v)| v.get("releaseDate").and_then(|d| d.as_str()))
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| today.clone()),
+        ),
+        ("date".to_string(), today),
+        ("count".to_string(), page.issues.len().to_string()),
+    ]);
+
+    let mut template = ctx.settings.release_notes.clone();
+    if version.is_none() {
+        template.header = template.jql_header.clone();
+    }
+    let template = &template;
+    let markdown = render_release_notes_markdown(template, &values, &groups);
+    let content = match input.format {
+        ReleaseNotesFormat::Markdown => markdown.clone(),
+        ReleaseNotesFormat::Html => render_release_notes_html(template, &values, &groups),
+    };
+
+    let posted = match input.post_to {
+        Some(ReleaseNotesTarget::Comment) => {
+            let issue_key = input.comment_issue_key.clone().unwrap_or_default();
+            let comment = ctx
+                .client
+                .add_comment_adf(&issue_key, &markdown_to_adf(&markdown), &ctx.auth)
+                .await
+                .mcp_context(
+                    error_ctx!("generate_release_notes", "post comment")
+                        .with_metadata("issue_key", issue_key.clone())
+                )?;
+            Some(json!({ "comment_on": issue_key, "comment_id": comment.get("id") }))
+        }
+        Some(ReleaseNotesTarget::VersionDescription) => {
+            // Version descriptions are plain text, so they always get the Markdown form
+            let id = version.as_ref().map(|(id, _)| id.clone()).unwrap_or_default();
+            let length = markdown.chars().count();
+            if length > MAX_VERSION_DESCRIPTION_CHARS {
+                return Err(rmcp::ErrorData::invalid_params(
+                    format!(
+                        "Release notes are {} characters, over the {} a version description holds",
+                        length, MAX_VERSION_DESCRIPTION_CHARS
+                    ),
+                    Some(json!({
+                        "hint": "Narrow the issues, turn off include_descriptions or post as a comment instead",
+                        "content": content
+                    })),
+                ));
+            }
+            ctx.client
+                .update_version(&id, &json!({ "description": markdown }), &ctx.auth)
+                .await
+                .mcp_context(
+                    error_ctx!("generate_release_notes", "update version description")
+                        .with_metadata("version_id", id.clone())
+                        .with_suggestions(get_version_suggestions)
+                )?;
+            Some(json!({ "version_description": id }))
+        }
+        None => None,
+    };
+
+    tracing::info!(
+        target: "mcp",
+        tool = "generate_release_notes",
+        issues = page.issues.len(),
+        groups = groups.len(),
+        posted = posted.is_some(),
+        "Release notes generated"
+    );
+
+    Ok(CallToolResult::structured(json!({
+        "version": version.as_ref().map(|(_, v)| version_summary(v)),
+        "jql": jql,
+        "format": match input.format {
+            ReleaseNotesFormat::Markdown => "markdown",
+            ReleaseNotesFormat::Html => "html",
+        },
+        "content": content,
+        "issue_count": page.issues.len(),
+        "groups": groups
+            .iter()
+            .map(|g| json!({ "name": g.name, "count": g.items.len() }))
+            .collect::<Vec<_>>(),
+        "truncated": !page.is_last,
+        "posted": posted
+    })))
+}
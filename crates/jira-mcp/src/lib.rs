@@ -2,9 +2,11 @@ pub mod board_utils;
 pub mod context;
 pub mod errors;
 pub mod handlers;
+pub mod metadata_cache;
 pub mod models;
 pub mod server;
 pub mod services;
+pub mod settings;
 pub mod utils;
 
 pub use context::JiraCtx;
@@ -6,6 +6,7 @@ use clap::Parser;
 use directories::ProjectDirs;
 use jira_client::config::JiraConfig;
 use jira_mcp::server::serve_stdio;
+use jira_mcp::settings::Settings;
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Registry};
 
 #[derive(Parser)]
@@ -23,6 +24,10 @@ struct Args {
     /// Jira API token
     #[arg(long, env = "JIRA_TOKEN")]
     token: String,
+
+    /// Path to config.toml (defaults to the platform config directory)
+    #[arg(long, env = "JIRA_MCP_CONFIG")]
+    config: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -32,10 +37,13 @@ async fn main() -> Result<()> {
     let args = Args::parse();
 
     let config = JiraConfig::new(args.jira_url, args.username, args.token);
+    let settings = Settings::load(args.config.as_deref())?;
 
     tracing::info!(base_url = %config.jira_base_url, "Starting Jira MCP server");
 
-    serve_stdio(config).await
+    tracing::info!(templates = settings.templates.len(), "Loaded settings");
+
+    serve_stdio(config, settings).await
 }
 
 fn init_tracing() -> tracing_appender::non_blocking::WorkerGuard {
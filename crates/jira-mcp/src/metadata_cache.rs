@@ -0,0 +1,64 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use jira_client::{auth::Auth, client::JiraClient};
+use jira_client::models::{Field, ProjectSummary};
+use serde_json::Value;
+
+const CACHE_TTL: Duration = Duration::from_secs(600);
+
+struct Entry<T> {
+    value: T,
+    fetched_at: Instant,
+}
+
+type Slot<T> = Arc<Mutex<Option<Entry<T>>>>;
+
+/// Process-wide cache for Jira metadata that rarely changes
+#[derive(Clone, Default)]
+pub struct MetadataCache {
+    fields: Slot<Vec<Field>>,
+    projects: Slot<Vec<ProjectSummary>>,
+    jql_autocomplete: Slot<Value>,
+}
+
+impl MetadataCache {
+    pub async fn fields(&self, client: &JiraClient, auth: &Auth) -> Result<Vec<Field>> {
+        get_or_fetch(&self.fields, client.list_fields(auth)).await
+    }
+
+    pub async fn projects(&self, client: &JiraClient, auth: &Auth) -> Result<Vec<ProjectSummary>> {
+        get_or_fetch(&self.projects, client.list_projects_summary(auth)).await
+    }
+
+    pub async fn jql_autocomplete(&self, client: &JiraClient, auth: &Auth) -> Result<Value> {
+        get_or_fetch(&self.jql_autocomplete, client.get_jql_autocomplete_data(auth)).await
+    }
+}
+
+async fn get_or_fetch<T, F>(slot: &Slot<T>, fetch: F) -> Result<T>
+where
+    T: Clone,
+    F: Future<Output = Result<T>>,
+{
+    if let Ok(guard) = slot.lock() {
+        if let Some(entry) = guard.as_ref() {
+            if entry.fetched_at.elapsed() < CACHE_TTL {
+                return Ok(entry.value.clone());
+            }
+        }
+    }
+
+    let value = fetch.await?;
+
+    if let Ok(mut guard) = slot.lock() {
+        *guard = Some(Entry {
+            value: value.clone(),
+            fetched_at: Instant::now(),
+        });
+    }
+
+    Ok(value)
+}
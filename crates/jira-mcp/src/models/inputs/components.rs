@@ -0,0 +1,63 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentAssigneeType {
+    ProjectDefault,
+    ComponentLead,
+    ProjectLead,
+    Unassigned,
+}
+
+impl ComponentAssigneeType {
+    pub fn as_jira(&self) -> &'static str {
+        match self {
+            ComponentAssigneeType::ProjectDefault => "PROJECT_DEFAULT",
+            ComponentAssigneeType::ComponentLead => "COMPONENT_LEAD",
+            ComponentAssigneeType::ProjectLead => "PROJECT_LEAD",
+            ComponentAssigneeType::Unassigned => "UNASSIGNED",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListComponentsInput {
+    pub project_key: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateComponentInput {
+    pub project_key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Account id of the component lead
+    #[serde(default)]
+    pub lead_account_id: Option<String>,
+    /// Who new issues with this component are assigned to
+    #[serde(default)]
+    pub assignee_type: Option<ComponentAssigneeType>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateComponentInput {
+    pub component_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Account id of the component lead
+    #[serde(default)]
+    pub lead_account_id: Option<String>,
+    #[serde(default)]
+    pub assignee_type: Option<ComponentAssigneeType>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DeleteComponentInput {
+    pub component_id: String,
+    /// Component (id or name, same project) that takes over the deleted component's issues
+    #[serde(default)]
+    pub move_issues_to: Option<String>,
+}
@@ -0,0 +1,76 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::{default_limit, default_search_fields, SearchOutputFormat};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListFiltersInput {
+    /// "favourite", "owned" or "all" (default: favourites plus owned)
+    #[serde(default)]
+    pub scope: Option<String>,
+    /// Match filters whose name contains this text
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RunFilterInput {
+    pub filter_id: u64,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+    #[serde(default = "default_search_fields")]
+    pub fields: String,
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+    #[serde(default)]
+    pub format: SearchOutputFormat,
+    #[serde(default)]
+    pub columns: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FilterSharePermissionInput {
+    /// "global", "authenticated", "project", "projectRole", "group" or "user"
+    #[serde(rename = "type")]
+    pub share_type: String,
+    /// Project key or id (for "project" and "projectRole")
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Project role id (for "projectRole")
+    #[serde(default)]
+    pub role_id: Option<String>,
+    /// Group name (for "group")
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Account id (for "user")
+    #[serde(default)]
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateFilterInput {
+    pub name: String,
+    pub jql: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub favourite: bool,
+    #[serde(default)]
+    pub share_permissions: Option<Vec<FilterSharePermissionInput>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateFilterInput {
+    pub filter_id: u64,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub jql: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub favourite: Option<bool>,
+    /// Replaces the filter's existing share permissions
+    #[serde(default)]
+    pub share_permissions: Option<Vec<FilterSharePermissionInput>>,
+}
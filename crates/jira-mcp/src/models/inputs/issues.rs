@@ -1,27 +1,180 @@
 use schemars::JsonSchema;
 use serde::Deserialize;
 
-use super::default_limit;
+use super::{
+    default_limit, default_search_fields, default_similar_limit, default_similarity_threshold,
+    default_tree_depth, default_true,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchOutputFormat {
+    /// Issues with their cleaned raw fields
+    #[default]
+    Raw,
+    /// One row per issue: key, summary, status, assignee, priority, updated plus `columns`
+    Compact,
+    /// Compact rows rendered as a Markdown table
+    Markdown,
+    /// Compact rows rendered as CSV
+    Csv,
+}
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct SearchIssuesInput {
     pub jql: String,
     #[serde(default = "default_limit")]
     pub limit: usize,
+    /// Comma separated field ids returned by the raw format
+    #[serde(default = "default_search_fields")]
     pub fields: String,
+    /// Opaque cursor returned as `next_page_token` by a previous search; pass it back to continue
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+    #[serde(default)]
+    pub format: SearchOutputFormat,
+    /// Extra columns for compact formats, by field display name (e.g. "Story Points") or id
     #[serde(default)]
-    pub start_at: Option<usize>,
+    pub columns: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueVerbosity {
+    /// Key, summary, status, type, assignee, priority, parent, labels and updated only
+    Minimal,
+    /// Fields visible on the board (or editable when there is no board)
+    #[default]
+    Standard,
+    /// Every non-empty field, unfiltered
+    Full,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IssueExpand {
+    /// Field values rendered as HTML
+    RenderedFields,
+    Changelog,
+    Transitions,
+    Operations,
+}
+
+impl IssueExpand {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueExpand::RenderedFields => "renderedFields",
+            IssueExpand::Changelog => "changelog",
+            IssueExpand::Transitions => "transitions",
+            IssueExpand::Operations => "operations",
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetIssueInput {
     pub key: String,
-    pub board_id: u64,
+    /// Board whose fields are shown; inferred from the issue's project when omitted
+    #[serde(default)]
+    pub board_id: Option<u64>,
+    /// Only these fields, by id or display name; overrides verbosity
+    #[serde(default)]
+    pub fields: Option<Vec<String>>,
+    /// Fields to drop from the result, by id or display name
+    #[serde(default)]
+    pub exclude_fields: Vec<String>,
+    #[serde(default)]
+    pub expand: Vec<IssueExpand>,
+    #[serde(default)]
+    pub verbosity: IssueVerbosity,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetIssueTreeInput {
+    /// Root of the tree, typically an initiative or epic
+    pub issue_key: String,
+    /// How many levels below the root to walk
+    #[serde(default = "default_tree_depth")]
+    pub max_depth: usize,
+    /// Story points field by display name or id; detected automatically when omitted
+    #[serde(default)]
+    pub story_points_field: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateSubtaskInput {
+    pub parent_key: String,
+    pub summary: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Subtask issue type name; the project's first subtask type is used when omitted
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    /// Additional Jira fields, same shape as create_issue
+    #[serde(default)]
+    pub fields: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetParentInput {
+    pub issue_key: String,
+    /// New parent issue key; omit to remove the current parent
+    #[serde(default)]
+    pub parent_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MoveToEpicInput {
+    pub epic_key: String,
+    pub issue_keys: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CloneIssueInput {
+    pub issue_key: String,
+    /// Field values replacing the copied ones, same shape as create_issue
+    #[serde(default)]
+    pub overrides: Option<serde_json::Value>,
+    /// Prefix for the copied summary when no summary override is given (default "CLONE - ")
+    #[serde(default)]
+    pub summary_prefix: Option<String>,
+    #[serde(default)]
+    pub include_subtasks: bool,
+    #[serde(default)]
+    pub include_links: bool,
+    #[serde(default = "default_true")]
+    pub include_labels: bool,
+    #[serde(default)]
+    pub include_attachments: bool,
+    /// Link type connecting the clone to the original (default "Cloners"); empty string skips the link
+    #[serde(default)]
+    pub link_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MoveIssueInput {
+    pub issue_key: String,
+    /// Target project key; defaults to the current project
+    #[serde(default)]
+    pub target_project: Option<String>,
+    /// Target issue type name; defaults to the current issue type
+    #[serde(default)]
+    pub target_issue_type: Option<String>,
+    /// Status in the target workflow; matched by name, then by status category, when omitted
+    #[serde(default)]
+    pub target_status: Option<String>,
+    /// Values for required target fields the issue has no value for, keyed by field id
+    #[serde(default)]
+    pub target_fields: Option<serde_json::Value>,
+    /// Only report lost fields, required fields and the status mapping
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetTransitionsInput {
     pub issue_key: String,
+    /// Pass "transitions.fields" to include each transition's required and optional screen fields
     #[serde(default)]
     pub expand: Option<String>,
 }
@@ -29,7 +182,16 @@ pub struct GetTransitionsInput {
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct TransitionIssueInput {
     pub issue_key: String,
-    pub transition_id: String,
+    /// Transition id from get_transitions; alternative to `to_status`
+    #[serde(default)]
+    pub transition_id: Option<String>,
+    /// Target status name or category ("To Do", "In Progress", "Done"), matched loosely
+    #[serde(default)]
+    pub to_status: Option<String>,
+    /// Walk through intermediate statuses when the target is not directly reachable
+    #[serde(default)]
+    pub follow_path: bool,
+    /// Screen fields by id, checked against the transition screen before calling Jira
     #[serde(default)]
     pub fields: Option<serde_json::Value>,
     #[serde(default)]
@@ -86,3 +248,24 @@ pub struct RemoveLabelInput {
     pub issue_key: String,
     pub labels: Vec<String>,
 }
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FindSimilarIssuesInput {
+    /// Project key to search in
+    pub project: String,
+    pub summary: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Maximum number of likely duplicates returned
+    #[serde(default = "default_similar_limit")]
+    pub limit: usize,
+    /// Minimum similarity score (0.0 - 1.0) for a candidate to be returned
+    #[serde(default = "default_similarity_threshold")]
+    pub min_score: f64,
+    /// Also consider resolved issues
+    #[serde(default = "default_true")]
+    pub include_resolved: bool,
+    /// Restrict candidates to one issue type
+    #[serde(default)]
+    pub issue_type: Option<String>,
+}
@@ -0,0 +1,66 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::default_limit;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BuildJqlInput {
+    /// Project key or name
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub issue_types: Option<Vec<String>>,
+    #[serde(default)]
+    pub statuses: Option<Vec<String>>,
+    /// "To Do", "In Progress" or "Done"
+    #[serde(default)]
+    pub status_category: Option<String>,
+    /// "me", "unassigned", an account id or a user's display name
+    #[serde(default)]
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub labels: Option<Vec<String>>,
+    /// "open"/"current", "future", "closed", a sprint id or a sprint name
+    #[serde(default)]
+    pub sprint: Option<String>,
+    /// Absolute date ("2024-01-31") or relative offset ("-7d")
+    #[serde(default)]
+    pub created_after: Option<String>,
+    /// Inclusive: a plain date covers that whole day
+    #[serde(default)]
+    pub created_before: Option<String>,
+    #[serde(default)]
+    pub updated_after: Option<String>,
+    /// Inclusive: a plain date covers that whole day
+    #[serde(default)]
+    pub updated_before: Option<String>,
+    /// Full-text search over summary, description and comments
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Filters keyed by field display name or id; array values match any of the values
+    #[serde(default)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Sort fields by display name or id with optional direction, e.g. ["priority DESC", "Story Points"]
+    #[serde(default)]
+    pub order_by: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ValidateJqlInput {
+    pub jql: String,
+    /// "strict" (default), "warn" or "none"
+    #[serde(default)]
+    pub validation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct JqlAutocompleteInput {
+    /// Field to suggest values for (e.g. "labels", "status", "project"); omit to search field and function names
+    #[serde(default)]
+    pub field_name: Option<String>,
+    /// Partial text to match: a field value when field_name is set, otherwise a field or function name
+    #[serde(default)]
+    pub query: Option<String>,
+    #[serde(default = "default_limit")]
+    pub max_results: usize,
+}
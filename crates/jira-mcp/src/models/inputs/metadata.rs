@@ -1,6 +1,8 @@
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+use super::{default_board_issue_limit, default_limit};
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListIssueTypesInput {
     #[serde(default)]
@@ -12,6 +14,25 @@ pub struct ListBoardsInput {
     pub project_key: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardSwimlane {
+    Assignee,
+    /// Parent issue (epic)
+    Parent,
+    Priority,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetBoardInput {
+    pub board_id: u64,
+    /// Group the issues in each column into swimlanes
+    #[serde(default)]
+    pub swimlane: Option<BoardSwimlane>,
+    #[serde(default = "default_board_issue_limit")]
+    pub limit: usize,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListSprintsInput {
     pub board_id: u64,
@@ -35,6 +56,34 @@ pub struct MoveToBacklogInput {
     pub issue_keys: Vec<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetBacklogInput {
+    pub board_id: u64,
+    /// Extra JQL to narrow the backlog, e.g. `issuetype = Bug`
+    #[serde(default)]
+    pub jql: Option<String>,
+    /// Position of the first issue to return (0-based)
+    #[serde(default)]
+    pub start_at: usize,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RankIssuesInput {
+    /// Issues to rank, in the order they should end up
+    pub issue_keys: Vec<String>,
+    /// Rank the issues directly before this issue
+    #[serde(default)]
+    pub before: Option<String>,
+    /// Rank the issues directly after this issue
+    #[serde(default)]
+    pub after: Option<String>,
+    /// Board whose rank field is used; defaults to the global Rank field
+    #[serde(default)]
+    pub board_id: Option<u64>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListLabelsInput {
     #[serde(default)]
@@ -1,14 +1,26 @@
 mod comments;
+mod components;
 mod fields;
+mod filters;
 mod issues;
+mod jql;
 mod metadata;
+mod sprints;
+mod templates;
 mod users;
+mod versions;
 
 pub use comments::*;
+pub use components::*;
 pub use fields::*;
+pub use filters::*;
 pub use issues::*;
+pub use jql::*;
 pub use metadata::*;
+pub use sprints::*;
+pub use templates::*;
 pub use users::*;
+pub use versions::*;
 
 pub fn default_limit() -> usize {
     20
@@ -17,3 +29,43 @@ pub fn default_limit() -> usize {
 pub fn default_search_limit() -> usize {
     50
 }
+
+pub fn default_board_issue_limit() -> usize {
+    200
+}
+
+pub fn default_bulk_edit_limit() -> usize {
+    100
+}
+
+pub fn default_search_fields() -> String {
+    "*all".to_string()
+}
+
+pub fn default_tree_depth() -> usize {
+    3
+}
+
+pub fn default_similar_limit() -> usize {
+    5
+}
+
+pub fn default_similarity_threshold() -> f64 {
+    0.3
+}
+
+pub fn default_sprint_days() -> i64 {
+    14
+}
+
+pub fn default_velocity_sprints() -> usize {
+    6
+}
+
+pub fn default_excerpt_length() -> usize {
+    200
+}
+
+pub fn default_true() -> bool {
+    true
+}
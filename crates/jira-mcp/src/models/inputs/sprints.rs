@@ -0,0 +1,89 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::{default_sprint_days, default_velocity_sprints};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateSprintInput {
+    pub board_id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub goal: Option<String>,
+    /// Date (YYYY-MM-DD) or ISO 8601 timestamp
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Date (YYYY-MM-DD) or ISO 8601 timestamp
+    #[serde(default)]
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateSprintInput {
+    pub sprint_id: u64,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub goal: Option<String>,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StartSprintInput {
+    pub sprint_id: u64,
+    /// Defaults to now
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Defaults to the sprint's planned end date, else start_date + duration_days
+    #[serde(default)]
+    pub end_date: Option<String>,
+    #[serde(default = "default_sprint_days")]
+    pub duration_days: i64,
+    #[serde(default)]
+    pub goal: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IncompleteIssuesTarget {
+    /// The board's next future sprint
+    #[default]
+    NextSprint,
+    /// A new sprint created on the same board
+    NewSprint,
+    Backlog,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CompleteSprintInput {
+    pub sprint_id: u64,
+    /// Where issues that are not done go
+    #[serde(default)]
+    pub incomplete_issues: IncompleteIssuesTarget,
+    /// Name for the sprint created by `new_sprint`; defaults to the next number after this sprint's name
+    #[serde(default)]
+    pub new_sprint_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DeleteSprintInput {
+    pub sprint_id: u64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SprintReportInput {
+    pub sprint_id: u64,
+    /// Board whose estimation field is used; defaults to the sprint's origin board
+    #[serde(default)]
+    pub board_id: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BoardVelocityInput {
+    pub board_id: u64,
+    /// Number of most recent closed sprints to include
+    #[serde(default = "default_velocity_sprints")]
+    pub sprints: usize,
+}
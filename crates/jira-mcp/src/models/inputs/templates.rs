@@ -0,0 +1,25 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListTemplatesInput {}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateFromTemplateInput {
+    /// Template name as shown by list_templates
+    pub template: String,
+    /// Project key; defaults to the template's project
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Values for the template's placeholders, e.g. {"component": "Checkout"}
+    #[serde(default)]
+    pub values: BTreeMap<String, String>,
+    /// Summary; required unless the template defines a summary pattern
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Additional Jira fields, same shape as create_issue; these win over template defaults
+    #[serde(default)]
+    pub fields: Option<serde_json::Value>,
+}
@@ -0,0 +1,134 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::default_excerpt_length;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionStatus {
+    Released,
+    Unreleased,
+    Archived,
+}
+
+impl VersionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionStatus::Released => "released",
+            VersionStatus::Unreleased => "unreleased",
+            VersionStatus::Archived => "archived",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListVersionsInput {
+    pub project_key: String,
+    #[serde(default)]
+    pub status: Option<VersionStatus>,
+    /// Fetch total and unresolved issue counts for each version (one request per version)
+    #[serde(default)]
+    pub include_counts: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateVersionInput {
+    pub project_key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Date as YYYY-MM-DD
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Planned release date as YYYY-MM-DD
+    #[serde(default)]
+    pub release_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateVersionInput {
+    pub version_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Date as YYYY-MM-DD
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Date as YYYY-MM-DD
+    #[serde(default)]
+    pub release_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReleaseVersionInput {
+    pub version_id: String,
+    /// Release date as YYYY-MM-DD; defaults to today
+    #[serde(default)]
+    pub release_date: Option<String>,
+    /// Version (id or name, same project) that receives the unresolved issues
+    #[serde(default)]
+    pub move_unresolved_to: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ArchiveVersionInput {
+    pub version_id: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseNotesGrouping {
+    #[default]
+    IssueType,
+    /// Issues with several components appear under each
+    Component,
+    /// Issues with several labels appear under each
+    Label,
+    /// One flat list
+    None,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseNotesFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseNotesTarget {
+    /// Comment on comment_issue_key, rendered from the Markdown notes
+    Comment,
+    /// Replace the version's description with the Markdown notes (needs version)
+    VersionDescription,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GenerateReleaseNotesInput {
+    /// Fix version id or name; a name needs project_key
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub project_key: Option<String>,
+    /// Select the issues with JQL instead of a version
+    #[serde(default)]
+    pub jql: Option<String>,
+    #[serde(default)]
+    pub group_by: ReleaseNotesGrouping,
+    #[serde(default)]
+    pub format: ReleaseNotesFormat,
+    /// Add a description excerpt under each issue
+    #[serde(default)]
+    pub include_descriptions: bool,
+    /// Maximum characters per description excerpt
+    #[serde(default = "default_excerpt_length")]
+    pub excerpt_length: usize,
+    #[serde(default)]
+    pub post_to: Option<ReleaseNotesTarget>,
+    /// Issue that receives the notes when post_to is comment
+    #[serde(default)]
+    pub comment_issue_key: Option<String>,
+}
@@ -1,6 +1,8 @@
 use schemars::{JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Serialize};
 
+use super::inputs::default_bulk_edit_limit;
+
 fn json_object_schema(_gen: &mut SchemaGenerator) -> Schema {
     serde_json::from_value(serde_json::json!({
         "type": "object",
@@ -14,6 +16,9 @@ fn json_object_schema(_gen: &mut SchemaGenerator) -> Schema {
 pub struct CreateIssueInput {
     #[schemars(schema_with = "json_object_schema")]
     pub fields: serde_json::Value,
+    /// Look for likely duplicates in the project first and return them instead of creating
+    #[serde(default)]
+    pub check_duplicates: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -60,3 +65,79 @@ pub struct FieldDef {
     #[serde(default, rename = "allowed_values")]
     pub allowed_values: serde_json::Value,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkIssueItem {
+    /// Local name for this item so later items can reference it via `parent_ref`
+    #[serde(default)]
+    pub ref_id: Option<String>,
+    /// The `ref_id` of an earlier item in the batch to use as this issue's parent (epic or story)
+    #[serde(default)]
+    pub parent_ref: Option<String>,
+    #[schemars(schema_with = "json_object_schema")]
+    pub fields: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkCreateIssuesInput {
+    pub issues: Vec<BulkIssueItem>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    Created,
+    Updated,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkCreateItemResult {
+    pub index: usize,
+    pub ref_id: Option<String>,
+    pub status: BulkItemStatus,
+    pub issue_key: Option<String>,
+    pub url: Option<String>,
+    pub error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkEditIssuesInput {
+    /// JQL selecting the issues to edit (use this or `issue_keys`)
+    #[serde(default)]
+    pub jql: Option<String>,
+    #[serde(default)]
+    pub issue_keys: Option<Vec<String>>,
+    /// Maximum number of issues taken from the JQL result
+    #[serde(default = "default_bulk_edit_limit")]
+    pub limit: usize,
+    /// Field values to set on every issue, same shape as update_issue
+    #[serde(default)]
+    #[schemars(schema_with = "json_object_schema")]
+    pub fields: Option<serde_json::Value>,
+    #[serde(default)]
+    pub add_labels: Option<Vec<String>>,
+    #[serde(default)]
+    pub remove_labels: Option<Vec<String>>,
+    /// Transition name or target status name, matched per issue
+    #[serde(default)]
+    pub transition: Option<String>,
+    /// Account id to assign, or "unassigned" to clear the assignee
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Only list the affected keys and planned changes
+    #[serde(default)]
+    pub dry_run: bool,
+    /// How many issues are edited at the same time (1-10, default 5)
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkEditItemResult {
+    pub issue_key: String,
+    pub status: BulkItemStatus,
+    pub actions: Vec<String>,
+    pub error: Option<String>,
+}
@@ -10,6 +10,7 @@ use rmcp::{
 use super::context::JiraCtx;
 use super::handlers;
 use super::models::*;
+use super::settings::Settings;
 
 #[derive(Clone)]
 pub struct JiraAssistantServer {
@@ -30,7 +31,7 @@ impl JiraAssistantServer {
     // Issue CRUD Operations
     // =========================================================================
 
-    #[tool(description = "Create a Jira issue")]
+    #[tool(description = "Create a Jira issue. Set check_duplicates to first look for similar issues in the project; likely duplicates are returned instead of creating")]
     async fn create_issue(
         &self,
         p: Parameters<CreateIssueInput>,
@@ -48,7 +49,7 @@ impl JiraAssistantServer {
         handlers::issues::update_issue_handler(input, &self.ctx).await
     }
 
-    #[tool(description = "Search issues by JQL query")]
+    #[tool(description = "Search issues by JQL query. Pages automatically up to 'limit'; pass the returned next_page_token back to continue. Includes is_last, plus an approximate total count on the first page (null when continuing with next_page_token). Use format 'compact', 'markdown' or 'csv' for one row per issue (key, summary, status, assignee, priority, updated plus 'columns' by display name)")]
     async fn search_issues(
         &self,
         p: Parameters<SearchIssuesInput>,
@@ -57,7 +58,16 @@ impl JiraAssistantServer {
         handlers::issues::search_issues_handler(input, &self.ctx).await
     }
 
-    #[tool(description = "Get a Jira issue with full fields (including custom), plus name mapping and schema")]
+    #[tool(description = "Find likely duplicates of a new issue: text-searches summary and description in the project and ranks hits by local token-overlap score. Use before create_issue to link instead of create")]
+    async fn find_similar_issues(
+        &self,
+        p: Parameters<FindSimilarIssuesInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::find_similar_issues_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Get a Jira issue with full fields (including custom), plus name mapping and schema. Fields are limited to those visible on board_id, or the project's default board when omitted. Use verbosity (minimal/standard/full), fields, exclude_fields and expand (renderedFields, changelog, transitions, operations) to control the size")]
     async fn get_issue(
         &self,
         p: Parameters<GetIssueInput>,
@@ -66,11 +76,175 @@ impl JiraAssistantServer {
         handlers::issues::get_issue_handler(input, &self.ctx).await
     }
 
+    #[tool(description = "Get an initiative, epic or story with all its children and subtasks, recursively (parent field, falling back to the legacy Epic Link field). Includes status category counts and story point sums per node and per level")]
+    async fn get_issue_tree(
+        &self,
+        p: Parameters<GetIssueTreeInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::get_issue_tree_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Create a subtask under an issue. Picks the project's subtask issue type automatically unless issue_type is given")]
+    async fn create_subtask(
+        &self,
+        p: Parameters<CreateSubtaskInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::create_subtask_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Change or remove an issue's parent (epic for stories, story/task for subtasks). Works for team-managed and company-managed projects, falling back to Epic Link where needed")]
+    async fn set_parent(
+        &self,
+        p: Parameters<SetParentInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::set_parent_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Move one or more issues into an epic. Returns per-issue results")]
+    async fn move_to_epic(
+        &self,
+        p: Parameters<MoveToEpicInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::move_to_epic_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Clone an issue: copies all editable fields (with optional overrides), optionally labels, subtasks, links and attachments, and links the clone to the original (link_type, default 'Cloners')")]
+    async fn clone_issue(
+        &self,
+        p: Parameters<CloneIssueInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::clone_issue_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Move an issue to another project and/or issue type. Compares create screens to report fields that will be lost or are required, maps the status by name or category, then runs Jira's bulk move and returns the new key. Use dry_run to see the plan only")]
+    async fn move_issue(
+        &self,
+        p: Parameters<MoveIssueInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::move_issue_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Create many issues at once (chunked to Jira's 50-issue bulk limit). Items may set ref_id and reference an earlier item via parent_ref to nest stories under epics created in the same batch. Returns per-item created/failed/skipped results")]
+    async fn bulk_create_issues(
+        &self,
+        p: Parameters<BulkCreateIssuesInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::bulk_create_issues_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Apply the same changes to every issue matching a JQL query or key list: field updates, label add/remove, assignee and a transition (by name or target status). Runs with bounded concurrency and reports per-issue results; use dry_run to list the affected keys first")]
+    async fn bulk_edit_issues(
+        &self,
+        p: Parameters<BulkEditIssuesInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::issues::bulk_edit_issues_handler(input, &self.ctx).await
+    }
+
+    // =========================================================================
+    // Issue Templates
+    // =========================================================================
+
+    #[tool(description = "List issue templates configured in config.toml, with their placeholders and required sections")]
+    async fn list_templates(
+        &self,
+        _p: Parameters<ListTemplatesInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        handlers::templates::list_templates_handler(&self.ctx).await
+    }
+
+    #[tool(description = "Create an issue from a configured template: fills {{placeholders}} in the summary and Markdown description from 'values', applies default labels/components/priority, and checks required sections and required fields before creating")]
+    async fn create_from_template(
+        &self,
+        p: Parameters<CreateFromTemplateInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::templates::create_from_template_handler(input, &self.ctx).await
+    }
+
+    // =========================================================================
+    // JQL
+    // =========================================================================
+
+    #[tool(description = "Build correctly quoted JQL from a structured filter (project, status/status category, assignee incl. 'me', labels, sprint 'open', date ranges, text, custom fields by name, order by). Names are resolved to ids so the result can be passed straight to search_issues")]
+    async fn build_jql(
+        &self,
+        p: Parameters<BuildJqlInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::jql::build_jql_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Validate JQL with Jira's parser. Returns errors matched to the clauses they concern plus the parsed structure")]
+    async fn validate_jql(
+        &self,
+        p: Parameters<ValidateJqlInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::jql::validate_jql_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "JQL autocomplete. With field_name, suggests values for that field (query filters them); without, lists matching field names (with operators) and functions")]
+    async fn jql_autocomplete(
+        &self,
+        p: Parameters<JqlAutocompleteInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::jql::jql_autocomplete_handler(input, &self.ctx).await
+    }
+
+    // =========================================================================
+    // Saved Filters
+    // =========================================================================
+
+    #[tool(description = "List saved filters: favourites and/or filters you own (scope 'favourite', 'owned' or 'all'), optionally matching a name")]
+    async fn list_filters(
+        &self,
+        p: Parameters<ListFiltersInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::filters::list_filters_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Run a saved filter's JQL. Accepts the same paging and output format options as search_issues")]
+    async fn run_filter(
+        &self,
+        p: Parameters<RunFilterInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::filters::run_filter_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Create a saved filter, optionally shared with projects, project roles, groups, users or everyone")]
+    async fn create_filter(
+        &self,
+        p: Parameters<CreateFilterInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::filters::create_filter_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Update a saved filter's name, JQL, description, favourite flag or share permissions")]
+    async fn update_filter(
+        &self,
+        p: Parameters<UpdateFilterInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::filters::update_filter_handler(input, &self.ctx).await
+    }
+
     // =========================================================================
     // Issue Transitions & Assignment
     // =========================================================================
 
-    #[tool(description = "Get available status transitions for a Jira issue")]
+    #[tool(description = "Get available status transitions for a Jira issue. With expand 'transitions.fields', each transition lists its required and optional screen fields with schema and allowed values")]
     async fn get_transitions(
         &self,
         p: Parameters<GetTransitionsInput>,
@@ -79,7 +253,7 @@ impl JiraAssistantServer {
         handlers::issues::get_transitions_handler(input, &self.ctx).await
     }
 
-    #[tool(description = "Transition an issue to a new status")]
+    #[tool(description = "Transition an issue by transition_id, or by to_status (status name or category such as \"Done\"; loose matches return candidates instead of running). When the status is not directly reachable, returns the path through the workflow; set follow_path to run it. Steps stop at screens with required fields that were not supplied")]
     async fn transition_issue(
         &self,
         p: Parameters<TransitionIssueInput>,
@@ -273,6 +447,15 @@ impl JiraAssistantServer {
         handlers::metadata::list_boards_handler(p.project_key, &self.ctx).await
     }
 
+    #[tool(description = "Board view: the board's columns with their statuses and WIP limits, current issues grouped by column (active sprint for scrum boards), optional swimlanes, and WIP limit violations")]
+    async fn get_board(
+        &self,
+        p: Parameters<GetBoardInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::boards::get_board_handler(input, &self.ctx).await
+    }
+
     #[tool(description = "List all sprints for a board")]
     async fn list_sprints(
         &self,
@@ -291,6 +474,69 @@ impl JiraAssistantServer {
         handlers::metadata::get_sprint_handler(input, &self.ctx).await
     }
 
+    #[tool(description = "Create a future sprint on a board, optionally with goal and start/end dates")]
+    async fn create_sprint(
+        &self,
+        p: Parameters<CreateSprintInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::create_sprint_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Update a sprint's name, goal or dates")]
+    async fn update_sprint(
+        &self,
+        p: Parameters<UpdateSprintInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::update_sprint_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Start a future sprint. Start defaults to now; end defaults to the planned end date or start + duration_days")]
+    async fn start_sprint(
+        &self,
+        p: Parameters<StartSprintInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::start_sprint_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Complete an active sprint. Issues not done move to the next future sprint (default), a new sprint, or the backlog")]
+    async fn complete_sprint(
+        &self,
+        p: Parameters<CompleteSprintInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::complete_sprint_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Delete a future sprint; its issues return to the backlog")]
+    async fn delete_sprint(
+        &self,
+        p: Parameters<DeleteSprintInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::delete_sprint_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Sprint report: issues by status category, committed vs completed story points (board estimation field), scope added/removed after the sprint started, and a daily burndown series")]
+    async fn sprint_report(
+        &self,
+        p: Parameters<SprintReportInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::sprint_report_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Velocity of a board over its last N closed sprints: committed and completed estimates per sprint, averages and trend. Uses Jira's velocity chart when available, otherwise computes from sprint issues and the board estimation field")]
+    async fn board_velocity(
+        &self,
+        p: Parameters<BoardVelocityInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::sprints::board_velocity_handler(input, &self.ctx).await
+    }
+
     #[tool(description = "Move one or more issues to a sprint")]
     async fn move_to_sprint(
         &self,
@@ -309,6 +555,122 @@ impl JiraAssistantServer {
         handlers::metadata::move_to_backlog_handler(input, &self.ctx).await
     }
 
+    #[tool(description = "Get a board's backlog in rank order (paginated with start_at), with each issue's position and the board's estimate")]
+    async fn get_backlog(
+        &self,
+        p: Parameters<GetBacklogInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::boards::get_backlog_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Reorder the backlog: rank issues (in the given order) directly before or after another issue. Pass board_id to use that board's rank field")]
+    async fn rank_issues(
+        &self,
+        p: Parameters<RankIssuesInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::boards::rank_issues_handler(input, &self.ctx).await
+    }
+
+    // =========================================================================
+    // Components
+    // =========================================================================
+
+    #[tool(description = "List a project's components with their lead, default assignee and issue count")]
+    async fn list_components(
+        &self,
+        p: Parameters<ListComponentsInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::components::list_components_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Create a component in a project, optionally with a lead and default assignee type")]
+    async fn create_component(
+        &self,
+        p: Parameters<CreateComponentInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::components::create_component_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Rename a component or change its description, lead or default assignee type")]
+    async fn update_component(
+        &self,
+        p: Parameters<UpdateComponentInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::components::update_component_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Delete a component. Pass move_issues_to (component id or name) to reassign its issues instead of just removing the component from them")]
+    async fn delete_component(
+        &self,
+        p: Parameters<DeleteComponentInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::components::delete_component_handler(input, &self.ctx).await
+    }
+
+    // =========================================================================
+    // Versions & Releases
+    // =========================================================================
+
+    #[tool(description = "List a project's versions (filter by released/unreleased/archived) with dates and issue status breakdown; include_counts adds total/unresolved issue counts")]
+    async fn list_versions(
+        &self,
+        p: Parameters<ListVersionsInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::versions::list_versions_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Create a version (fix version) in a project with optional description, start and release dates (YYYY-MM-DD)")]
+    async fn create_version(
+        &self,
+        p: Parameters<CreateVersionInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::versions::create_version_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Rename a version or change its description, start or release date")]
+    async fn update_version(
+        &self,
+        p: Parameters<UpdateVersionInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::versions::update_version_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Release a version (release date defaults to today). Pass move_unresolved_to (version id or name) to have Jira move its unresolved issues to that version as part of the release")]
+    async fn release_version(
+        &self,
+        p: Parameters<ReleaseVersionInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::versions::release_version_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Archive a version so it no longer shows in version pickers")]
+    async fn archive_version(
+        &self,
+        p: Parameters<ArchiveVersionInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::versions::archive_version_handler(input, &self.ctx).await
+    }
+
+    #[tool(description = "Generate release notes for a fix version (id, or name with project_key) or a JQL query: issues grouped by issue type, component or label, with optional description excerpts, as Markdown or HTML using the configured [release_notes] template. Optionally post them as a comment or as the version description")]
+    async fn generate_release_notes(
+        &self,
+        p: Parameters<GenerateReleaseNotesInput>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let Parameters(input) = p;
+        handlers::versions::generate_release_notes_handler(input, &self.ctx).await
+    }
+
     // =========================================================================
     // Projects & Users
     // =========================================================================
@@ -344,13 +706,13 @@ impl ServerHandler for JiraAssistantServer {
             protocol_version: ProtocolVersion::V_2025_06_18,
             capabilities: ServerCapabilities::builder().enable_tools().build(),
             server_info: Implementation::from_build_env(),
-            instructions: Some("Jira Assistant tools: create_issue, update_issue, search_issues, list_fields, get_field_details, list_issue_types, list_boards, list_sprints, get_issue, get_user_info, list_projects, search_users, get_transitions, transition_issue, add_comment, get_comments, assign_issue, add_watcher, remove_watcher, link_issues, move_to_sprint, get_watchers, delete_issue_link, move_to_backlog, get_sprint, update_comment, add_label, remove_label, delete_comment, list_link_types, list_labels".into()),
+            instructions: Some("Jira Assistant tools: create_issue, update_issue, search_issues, find_similar_issues, bulk_create_issues, bulk_edit_issues, list_fields, get_field_details, list_issue_types, list_boards, get_board, list_sprints, get_issue, get_issue_tree, create_subtask, set_parent, move_to_epic, clone_issue, move_issue, list_templates, create_from_template, build_jql, validate_jql, jql_autocomplete, list_filters, run_filter, create_filter, update_filter, get_user_info, list_projects, search_users, get_transitions, transition_issue, add_comment, get_comments, assign_issue, add_watcher, remove_watcher, link_issues, move_to_sprint, create_sprint, update_sprint, start_sprint, complete_sprint, delete_sprint, sprint_report, board_velocity, get_watchers, delete_issue_link, move_to_backlog, get_backlog, rank_issues, get_sprint, update_comment, add_label, remove_label, delete_comment, list_link_types, list_labels, list_components, create_component, update_component, delete_component, list_versions, create_version, update_version, release_version, archive_version, generate_release_notes".into()),
         }
     }
 }
 
-pub async fn serve_stdio(config: JiraConfig) -> Result<()> {
-    let ctx = JiraCtx::from_config(&config).map_err(|e| {
+pub async fn serve_stdio(config: JiraConfig, settings: Settings) -> Result<()> {
+    let ctx = JiraCtx::from_config(&config, settings).map_err(|e| {
         anyhow::anyhow!("Failed to create Jira context: {:?}", e)
     })?;
 
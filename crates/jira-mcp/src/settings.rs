@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+/// Optional server settings read from `config.toml`.
+///
+/// The file lives in the platform config directory (e.g. `~/.config/jira-mcp/config.toml`)
+/// unless a path is given with `--config` / `JIRA_MCP_CONFIG`. A missing file means defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Issue templates by name
+    pub templates: BTreeMap<String, IssueTemplate>,
+    /// Board used for a project's field visibility when get_issue has no board_id, by project key
+    pub default_boards: BTreeMap<String, u64>,
+    /// Layout of generate_release_notes output, from the `[release_notes]` table
+    pub release_notes: ReleaseNotesTemplate,
+}
+
+/// A named issue template from the `[templates.<name>]` table
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IssueTemplate {
+    /// What the template is for, shown by list_templates
+    pub about: Option<String>,
+    pub issue_type: String,
+    /// Default project key when create_from_template is called without one
+    pub project: Option<String>,
+    /// Summary pattern, may contain `{{placeholders}}`
+    pub summary: Option<String>,
+    /// Markdown description skeleton with `{{placeholders}}`
+    pub description: String,
+    pub labels: Vec<String>,
+    pub components: Vec<String>,
+    pub priority: Option<String>,
+    /// Markdown headings that must have content once placeholders are filled
+    pub required_sections: Vec<String>,
+}
+
+/// Release notes layout. Every part may use `{{version}}`, `{{project}}`, `{{release_date}}`,
+/// `{{date}}` and `{{count}}`; `item` also gets the issue's `{{key}}`, `{{summary}}`, `{{type}}`,
+/// `{{status}}`, `{{priority}}`, `{{assignee}}`, `{{components}}`, `{{labels}}` and `{{url}}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReleaseNotesTemplate {
+    pub title: String,
+    /// Text between the title and the first group
+    pub header: String,
+    /// Header used instead when the notes come from a JQL query rather than a version
+    pub jql_header: String,
+    /// One line per issue
+    pub item: String,
+    pub footer: String,
+}
+
+impl Default for ReleaseNotesTemplate {
+    fn default() -> Self {
+        ReleaseNotesTemplate {
+            title: "Release notes: {{version}}".to_string(),
+            header: "{{count}} issue(s), released {{release_date}}".to_string(),
+            jql_header: "{{count}} issue(s) as of {{date}}".to_string(),
+            item: "{{key}}: {{summary}}".to_string(),
+            footer: String::new(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(p) => p.to_path_buf(),
+            None => match default_settings_path() {
+                Some(p) if p.exists() => p,
+                _ => return Ok(Settings::default()),
+            },
+        };
+
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+        toml::from_str(&raw).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+}
+
+pub fn default_settings_path() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "jira-mcp").map(|dirs| dirs.config_dir().join("config.toml"))
+}
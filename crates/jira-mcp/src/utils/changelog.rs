@@ -0,0 +1,117 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use super::parse_jira_datetime;
+
+/// Changelog items of an issue (fetched with `expand=changelog`) matching a predicate, oldest first
+pub fn changelog_items<F>(issue: &Value, matches: F) -> Vec<(DateTime<Utc>, &Value)>
+where
+    F: Fn(&Value) -> bool,
+{
+    let mut out: Vec<(DateTime<Utc>, &Value)> = issue
+        .get("changelog")
+        .and_then(|c| c.get("histories"))
+        .and_then(|h| h.as_array())
+        .map(|histories| {
+            histories
+                .iter()
+                .filter_map(|h| {
+                    let at = parse_jira_datetime(h.get("created")?.as_str()?)?;
+                    let items = h.get("items")?.as_array()?;
+                    Some(items.iter().filter(|i| matches(i)).map(move |i| (at, i)))
+                })
+                .flatten()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    out.sort_by_key(|(at, _)| *at);
+    out
+}
+
+/// Sprint ids in a changelog `from`/`to` value such as `"123, 456"`
+pub fn sprint_ids_in(value: Option<&Value>) -> Vec<u64> {
+    value
+        .and_then(|v| v.as_str())
+        .map(|s| s.split(',').filter_map(|id| id.trim().parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Times an issue joined (`true`) or left (`false`) a sprint, oldest first
+pub fn sprint_membership_changes(issue: &Value, sprint_id: u64) -> Vec<(DateTime<Utc>, bool)> {
+    changelog_items(issue, |item| item.get("field").and_then(|f| f.as_str()) == Some("Sprint"))
+        .into_iter()
+        .filter_map(|(at, item)| {
+            let before = sprint_ids_in(item.get("from")).contains(&sprint_id);
+            let after = sprint_ids_in(item.get("to")).contains(&sprint_id);
+            (before != after).then_some((at, after))
+        })
+        .collect()
+}
+
+/// Whether the issue was in the sprint at `at`, given its membership changes and whether it is in the sprint now
+pub fn in_sprint_at(changes: &[(DateTime<Utc>, bool)], created: Option<DateTime<Utc>>, in_sprint_now: bool, at: DateTime<Utc>) -> bool {
+    if created.is_some_and(|c| c > at) {
+        return false;
+    }
+
+    match changes.iter().rev().find(|(t, _)| *t <= at) {
+        Some((_, joined)) => *joined,
+        // Before the first change the issue was in the opposite state of that change
+        None => match changes.first() {
+            Some((_, joined)) => !joined,
+            None => in_sprint_now,
+        },
+    }
+}
+
+/// Value of a numeric field at `at`, rewinding the changes made after it from the current value
+pub fn numeric_value_at(issue: &Value, field_id: &str, current: Option<f64>, at: DateTime<Utc>) -> Option<f64> {
+    let changes = changelog_items(issue, |item| item.get("fieldId").and_then(|f| f.as_str()) == Some(field_id));
+
+    match changes.iter().find(|(t, _)| *t > at) {
+        Some((_, item)) => item
+            .get("fromString")
+            .or_else(|| item.get("from"))
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.trim().parse().ok()),
+        None => current,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, d, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn in_sprint_at_without_changes_uses_current_membership() {
+        assert!(in_sprint_at(&[], Some(day(1)), true, day(5)));
+        assert!(!in_sprint_at(&[], Some(day(1)), false, day(5)));
+    }
+
+    #[test]
+    fn in_sprint_at_is_false_before_the_issue_existed() {
+        assert!(!in_sprint_at(&[], Some(day(10)), true, day(5)));
+    }
+
+    #[test]
+    fn in_sprint_at_follows_the_last_change_before_the_time() {
+        let changes = [(day(3), true), (day(7), false)];
+
+        assert!(!in_sprint_at(&changes, None, false, day(2)));
+        assert!(in_sprint_at(&changes, None, false, day(5)));
+        assert!(!in_sprint_at(&changes, None, false, day(8)));
+    }
+
+    #[test]
+    fn in_sprint_at_infers_state_before_the_first_change() {
+        // Removed on day 7 without a recorded add: it was in the sprint before that
+        assert!(in_sprint_at(&[(day(7), false)], None, false, day(4)));
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+
+/// Parse a Jira timestamp: RFC 3339 or Jira's `2024-01-15T10:00:00.000+0000` form
+pub fn parse_jira_datetime(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .map(|d| d.with_timezone(&Utc))
+        .ok()
+}
+
+/// Accept a date (`2024-01-15`) or a full timestamp and normalize it for the Agile API
+pub fn sprint_date_param(value: &str) -> Option<String> {
+    let value = value.trim();
+
+    if let Some(parsed) = parse_jira_datetime(value) {
+        return Some(format_jira_datetime(parsed));
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|d| format_jira_datetime(d.and_utc()))
+}
+
+pub fn format_jira_datetime(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
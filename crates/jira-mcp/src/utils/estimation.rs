@@ -0,0 +1,32 @@
+use jira_client::models::Field;
+use serde_json::Value;
+
+/// Display names Jira uses for story point fields (company- and team-managed projects)
+pub const STORY_POINT_FIELD_NAMES: &[&str] = &["Story Points", "Story point estimate"];
+
+/// Ids of the story point fields present on this instance
+pub fn story_point_field_ids(fields: &[Field]) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|f| STORY_POINT_FIELD_NAMES.iter().any(|n| f.name.eq_ignore_ascii_case(n)))
+        .map(|f| f.id.clone())
+        .collect()
+}
+
+/// First numeric value among the given field ids
+pub fn estimate_value(issue_fields: &Value, field_ids: &[String]) -> Option<f64> {
+    field_ids
+        .iter()
+        .find_map(|id| issue_fields.get(id).and_then(|v| v.as_f64()))
+}
+
+/// Status category name ("To Do", "In Progress", "Done") of an issue's status
+pub fn status_category_name(issue_fields: &Value) -> String {
+    issue_fields
+        .get("status")
+        .and_then(|s| s.get("statusCategory"))
+        .and_then(|c| c.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("Unknown")
+        .to_string()
+}
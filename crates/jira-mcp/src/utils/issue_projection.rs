@@ -0,0 +1,171 @@
+use jira_client::utils::adf_collect_text;
+use serde_json::{Map, Value};
+
+/// Jira fields every compact row is built from
+pub const COMPACT_BASE_FIELDS: &[&str] = &["summary", "status", "assignee", "priority", "updated"];
+
+const COMPACT_BASE_COLUMNS: &[&str] = &["key", "summary", "status", "assignee", "priority", "updated"];
+
+/// Most characters of rich text (ADF) kept in a compact cell
+const RICH_TEXT_CELL_CHARS: usize = 200;
+
+/// A requested column resolved against the `names` expansion of a search
+#[derive(Debug, Clone)]
+pub struct ProjectedColumn {
+    pub field_id: String,
+    pub label: String,
+}
+
+/// Resolve requested columns (display names or field ids) to field ids.
+/// Returns the resolved columns and the requested names that matched nothing.
+pub fn resolve_columns(
+    requested: &[String],
+    names: &Map<String, Value>,
+) -> (Vec<ProjectedColumn>, Vec<String>) {
+    let mut resolved = Vec::new();
+    let mut unresolved = Vec::new();
+
+    for col in requested {
+        let wanted = col.trim();
+
+        if wanted.is_empty() {
+            continue;
+        }
+
+        let by_name = names.iter().find(|(_, name)| {
+            name.as_str()
+                .map(|n| n.eq_ignore_ascii_case(wanted))
+                .unwrap_or(false)
+        });
+
+        let found = match by_name {
+            Some((id, name)) => Some((id.clone(), name.as_str().unwrap_or(id).to_string())),
+            None => names
+                .get(wanted)
+                .map(|name| (wanted.to_string(), name.as_str().unwrap_or(wanted).to_string())),
+        };
+
+        match found {
+            Some((field_id, label)) => resolved.push(ProjectedColumn { field_id, label }),
+            None => unresolved.push(wanted.to_string()),
+        }
+    }
+
+    (resolved, unresolved)
+}
+
+/// Column headers for a projection: the fixed base columns followed by the extra ones
+pub fn column_headers(extra: &[ProjectedColumn]) -> Vec<String> {
+    COMPACT_BASE_COLUMNS
+        .iter()
+        .map(|c| c.to_string())
+        .chain(extra.iter().map(|c| c.label.clone()))
+        .collect()
+}
+
+/// Build a compact row for a search result issue
+pub fn project_issue(issue: &Value, extra: &[ProjectedColumn]) -> Map<String, Value> {
+    let fields = issue.get("fields").cloned().unwrap_or(Value::Null);
+    let text = |v: Option<&Value>| v.and_then(|s| s.as_str()).map(|s| Value::String(s.to_string())).unwrap_or(Value::Null);
+
+    let mut row = Map::new();
+    row.insert("key".to_string(), text(issue.get("key")));
+    row.insert("summary".to_string(), text(fields.get("summary")));
+    row.insert("status".to_string(), text(fields.get("status").and_then(|s| s.get("name"))));
+    row.insert("assignee".to_string(), text(fields.get("assignee").and_then(|a| a.get("displayName"))));
+    row.insert("priority".to_string(), text(fields.get("priority").and_then(|p| p.get("name"))));
+    row.insert("updated".to_string(), text(fields.get("updated")));
+
+    for col in extra {
+        let value = fields.get(&col.field_id).map(simplify_value).unwrap_or(Value::Null);
+        row.insert(col.label.clone(), value);
+    }
+
+    row
+}
+
+pub fn adf_to_plain_text(adf: &Value) -> String {
+    let mut result = String::new();
+    adf_collect_text(adf, &mut result);
+    result.trim().to_string()
+}
+
+/// Reduce a Jira field value to its human readable form; rich text becomes truncated plain text
+pub fn simplify_value(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) => {
+            if obj.get("type").and_then(|t| t.as_str()) == Some("doc") {
+                let text = adf_to_plain_text(value).split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.chars().count() <= RICH_TEXT_CELL_CHARS {
+                    return Value::String(text);
+                }
+                let cut: String = text.chars().take(RICH_TEXT_CELL_CHARS).collect();
+                return Value::String(format!("{}…", cut.trim_end()));
+            }
+
+            for key in ["displayName", "name", "value", "key"] {
+                if let Some(v) = obj.get(key).filter(|v| !v.is_null()) {
+                    return v.clone();
+                }
+            }
+
+            Value::Object(obj.clone())
+        }
+        Value::Array(arr) => Value::Array(arr.iter().map(simplify_value).collect()),
+        other => other.clone(),
+    }
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(arr) => arr.iter().map(cell_text).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+pub fn render_markdown_table(headers: &[String], rows: &[Map<String, Value>]) -> String {
+    let escape = |s: String| s.replace('|', "\\|").replace(['\r', '\n'], " ");
+
+    let header_cells: Vec<String> = headers.iter().map(|h| escape(h.clone())).collect();
+
+    let mut out = String::new();
+    out.push_str(&format!("| {} |\n", header_cells.join(" | ")));
+    out.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+
+    for row in rows {
+        let cells: Vec<String> = headers
+            .iter()
+            .map(|h| escape(row.get(h).map(cell_text).unwrap_or_default()))
+            .collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+
+    out
+}
+
+pub fn render_csv(headers: &[String], rows: &[Map<String, Value>]) -> String {
+    let escape = |s: String| {
+        if s.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s
+        }
+    };
+
+    let mut out = String::new();
+    out.push_str(&headers.iter().map(|h| escape(h.clone())).collect::<Vec<_>>().join(","));
+    out.push('\n');
+
+    for row in rows {
+        let cells: Vec<String> = headers
+            .iter()
+            .map(|h| escape(row.get(h).map(cell_text).unwrap_or_default()))
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,149 @@
+use jira_client::models::Field;
+use serde_json::{json, Value};
+
+/// Quote a value for use in JQL, escaping backslashes and double quotes
+pub fn quote_jql_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Quote a list of values for an `in (...)` clause
+pub fn quote_jql_list(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|v| quote_jql_value(v)).collect();
+    format!("({})", quoted.join(", "))
+}
+
+/// Find a field by id or display name (case-insensitive)
+pub fn find_field<'a>(fields: &'a [Field], name_or_id: &str) -> Option<&'a Field> {
+    let wanted = name_or_id.trim();
+    fields
+        .iter()
+        .find(|f| f.id == wanted)
+        .or_else(|| fields.iter().find(|f| f.name.eq_ignore_ascii_case(wanted)))
+}
+
+/// The name to reference a field by in JQL: `cf[12345]` for custom fields, otherwise the first
+/// clause name Jira lists for it (`fixVersion` rather than the id `fixVersions`)
+pub fn field_clause_name(field: &Field) -> String {
+    if let Some(cf) = field.clause_names.iter().find(|c| c.starts_with("cf[")) {
+        return cf.clone();
+    }
+
+    if let Some(num) = field.id.strip_prefix("customfield_") {
+        return format!("cf[{}]", num);
+    }
+
+    if let Some(name) = field.clause_names.iter().find(|c| **c != field.id) {
+        return name.clone();
+    }
+
+    field.id.clone()
+}
+
+/// Upper-bound date clause that includes the whole day for a plain `YYYY-MM-DD` date
+/// (`created < "2024-02-01"`); datetimes and relative offsets are compared with `<=` as given
+pub fn date_before_clause(field: &str, value: &str) -> String {
+    let trimmed = value.trim();
+    match chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d").ok().and_then(|d| d.succ_opt()) {
+        Some(next) if trimmed.len() == 10 => {
+            format!("{} < {}", field, quote_jql_value(&next.format("%Y-%m-%d").to_string()))
+        }
+        _ => format!("{} <= {}", field, quote_jql_value(trimmed)),
+    }
+}
+
+/// Whether a field holds free text and must be matched with `~` instead of `=`
+pub fn is_text_field(field: &Field) -> bool {
+    let Some(schema) = field.schema.as_ref() else {
+        return false;
+    };
+
+    let ty = schema.get("type").and_then(|t| t.as_str()).unwrap_or("");
+    let custom = schema.get("custom").and_then(|c| c.as_str()).unwrap_or("");
+
+    if ty != "string" {
+        return false;
+    }
+
+    matches!(field.id.as_str(), "summary" | "description" | "environment")
+        || custom.ends_with(":textfield")
+        || custom.ends_with(":textarea")
+}
+
+/// Map loose status category input to the category name JQL expects
+pub fn normalize_status_category(value: &str) -> Option<&'static str> {
+    match value.trim().to_lowercase().replace(['_', '-'], " ").as_str() {
+        "to do" | "todo" | "new" | "open" => Some("To Do"),
+        "in progress" | "indeterminate" | "doing" => Some("In Progress"),
+        "done" | "complete" | "completed" | "closed" => Some("Done"),
+        _ => None,
+    }
+}
+
+pub fn sprint_clause(value: &str) -> String {
+    match value.trim().to_lowercase().as_str() {
+        "open" | "current" | "active" => "sprint in openSprints()".to_string(),
+        "future" => "sprint in futureSprints()".to_string(),
+        "closed" => "sprint in closedSprints()".to_string(),
+        _ if value.trim().chars().all(|c| c.is_ascii_digit()) => format!("sprint = {}", value.trim()),
+        _ => format!("sprint = {}", quote_jql_value(value.trim())),
+    }
+}
+
+/// Heuristic for Atlassian account ids (`557058:...` or 24 hex characters)
+pub fn looks_like_account_id(value: &str) -> bool {
+    value.contains(':') || (value.len() == 24 && value.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Split "Story Points DESC" into ("Story Points", Some("DESC"))
+pub fn split_order_direction(entry: &str) -> (&str, Option<&'static str>) {
+    let trimmed = entry.trim();
+
+    if let Some((field, dir)) = trimmed.rsplit_once(' ') {
+        if dir.eq_ignore_ascii_case("desc") {
+            return (field.trim(), Some("DESC"));
+        }
+
+        if dir.eq_ignore_ascii_case("asc") {
+            return (field.trim(), Some("ASC"));
+        }
+    }
+
+    (trimmed, None)
+}
+
+/// Flatten the `where` tree of a parsed JQL structure into one entry per field clause
+pub fn flatten_jql_clauses(node: &Value, out: &mut Vec<Value>) {
+    if let Some(children) = node.get("clauses").and_then(|c| c.as_array()) {
+        for child in children {
+            flatten_jql_clauses(child, out);
+        }
+        return;
+    }
+
+    let Some(field) = node
+        .get("field")
+        .and_then(|f| f.get("name"))
+        .and_then(|n| n.as_str())
+    else {
+        return;
+    };
+
+    let operand = node.get("operand").map(|o| {
+        o.get("value")
+            .or_else(|| o.get("values"))
+            .or_else(|| o.get("function"))
+            .cloned()
+            .unwrap_or_else(|| o.clone())
+    });
+
+    out.push(json!({
+        "field": field,
+        "operator": node.get("operator").cloned().unwrap_or(Value::Null),
+        "operand": operand.unwrap_or(Value::Null),
+    }));
+}
+
+/// Remove the `<b>` highlighting Jira adds to autocomplete display names
+pub fn strip_highlight(text: &str) -> String {
+    text.replace("<b>", "").replace("</b>", "")
+}
@@ -1,3 +1,21 @@
+pub mod changelog;
+pub mod dates;
+pub mod estimation;
 pub mod field_processing;
+pub mod issue_projection;
+pub mod jql;
+pub mod release_notes;
+pub mod similarity;
+pub mod templates;
+pub mod workflow;
 
+pub use changelog::*;
+pub use dates::*;
+pub use estimation::*;
 pub use field_processing::*;
+pub use issue_projection::*;
+pub use jql::*;
+pub use release_notes::*;
+pub use similarity::*;
+pub use templates::*;
+pub use workflow::*;
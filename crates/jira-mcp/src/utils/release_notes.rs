@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use crate::settings::ReleaseNotesTemplate;
+
+use super::fill_template;
+
+/// One issue in the release notes: the values for the item template and an optional excerpt
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseNoteItem {
+    pub values: BTreeMap<String, String>,
+    pub excerpt: Option<String>,
+}
+
+/// A titled group of items; an empty name renders the items without a heading
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseNotesGroup {
+    pub name: String,
+    pub items: Vec<ReleaseNoteItem>,
+}
+
+/// First paragraph of a text, cut at a word boundary to at most `max_chars` characters
+pub fn excerpt(text: &str, max_chars: usize) -> Option<String> {
+    let paragraph = text
+        .split("\n\n")
+        .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+        .find(|p| !p.is_empty())?;
+
+    if paragraph.chars().count() <= max_chars {
+        return Some(paragraph);
+    }
+
+    let cut: String = paragraph.chars().take(max_chars).collect();
+    let cut = match cut.rfind(' ') {
+        Some(pos) if pos > 0 => cut[..pos].to_string(),
+        _ => cut,
+    };
+    Some(format!("{}…", cut.trim_end_matches(|c: char| c.is_ascii_punctuation())))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Fill a template, leaving unknown placeholders out rather than showing `{{name}}`
+fn fill(template: &str, values: &BTreeMap<String, String>) -> String {
+    let (text, missing) = fill_template(template, values);
+    if missing.is_empty() {
+        return text;
+    }
+
+    let blanks: BTreeMap<String, String> = missing.into_iter().map(|m| (m, String::new())).collect();
+    fill_template(&text, &blanks).0
+}
+
+pub fn render_release_notes_markdown(
+    template: &ReleaseNotesTemplate,
+    values: &BTreeMap<String, String>,
+    groups: &[ReleaseNotesGroup],
+) -> String {
+    let mut out = format!("# {}\n", fill(&template.title, values));
+
+    let header = fill(&template.header, values);
+    if !header.trim().is_empty() {
+        out.push_str(&format!("\n{}\n", header.trim()));
+    }
+
+    for group in groups {
+        if !group.name.is_empty() {
+            out.push_str(&format!("\n## {}\n", group.name));
+        }
+        out.push('\n');
+
+        for item in &group.items {
+            let mut item_values = values.clone();
+            item_values.extend(item.values.clone());
+            out.push_str(&format!("- {}\n", fill(&template.item, &item_values)));
+
+            if let Some(excerpt) = &item.excerpt {
+                out.push_str(&format!("  {}\n", excerpt));
+            }
+        }
+    }
+
+    let footer = fill(&template.footer, values);
+    if !footer.trim().is_empty() {
+        out.push_str(&format!("\n{}\n", footer.trim()));
+    }
+
+    out
+}
+
+/// Same layout as the Markdown notes; values are escaped, template text is used as is
+pub fn render_release_notes_html(
+    template: &ReleaseNotesTemplate,
+    values: &BTreeMap<String, String>,
+    groups: &[ReleaseNotesGroup],
+) -> String {
+    let escaped = |values: &BTreeMap<String, String>| -> BTreeMap<String, String> {
+        values.iter().map(|(k, v)| (k.clone(), escape_html(v))).collect()
+    };
+    let values = escaped(values);
+
+    let mut out = format!("<h1>{}</h1>\n", fill(&template.title, &values));
+
+    let header = fill(&template.header, &values);
+    if !header.trim().is_empty() {
+        out.push_str(&format!("<p>{}</p>\n", header.trim()));
+    }
+
+    for group in groups {
+        if !group.name.is_empty() {
+            out.push_str(&format!("<h2>{}</h2>\n", escape_html(&group.name)));
+        }
+        out.push_str("<ul>\n");
+
+        for item in &group.items {
+            let mut item_values = values.clone();
+            item_values.extend(escaped(&item.values));
+            out.push_str(&format!("  <li>{}", fill(&template.item, &item_values)));
+
+            if let Some(excerpt) = &item.excerpt {
+                out.push_str(&format!("<br><small>{}</small>", escape_html(excerpt)));
+            }
+            out.push_str("</li>\n");
+        }
+
+        out.push_str("</ul>\n");
+    }
+
+    let footer = fill(&template.footer, &values);
+    if !footer.trim().is_empty() {
+        out.push_str(&format!("<p>{}</p>\n", footer.trim()));
+    }
+
+    out
+}
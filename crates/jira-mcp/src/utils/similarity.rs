@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+/// Common words that carry no signal when comparing issue text
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "can", "cannot", "does", "doesn",
+    "for", "from", "has", "have", "if", "in", "into", "is", "it", "its", "not", "of", "on", "or",
+    "should", "so", "that", "the", "their", "then", "there", "this", "to", "was", "when", "where",
+    "which", "while", "will", "with", "won", "would", "after", "before", "we", "you", "our",
+];
+
+/// Weight of the summary when both sides have a description to compare
+const SUMMARY_WEIGHT: f64 = 0.7;
+
+/// Lowercased word tokens of a text without stop words and one-letter noise
+pub fn text_tokens(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() > 1)
+        .map(|w| w.to_lowercase())
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Jaccard similarity of two token sets, 0.0 when either is empty
+pub fn token_overlap(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let shared = a.intersection(b).count();
+    let total = a.union(b).count();
+
+    shared as f64 / total as f64
+}
+
+/// Score a candidate against the new issue; the description only counts when both sides have one
+pub fn issue_similarity(
+    summary: &BTreeSet<String>,
+    description: &BTreeSet<String>,
+    candidate_summary: &BTreeSet<String>,
+    candidate_description: &BTreeSet<String>,
+) -> f64 {
+    let summary_score = token_overlap(summary, candidate_summary);
+
+    if description.is_empty() || candidate_description.is_empty() {
+        return summary_score;
+    }
+
+    let body_score = token_overlap(description, candidate_description);
+
+    SUMMARY_WEIGHT * summary_score + (1.0 - SUMMARY_WEIGHT) * body_score
+}
+
+/// The most specific search terms: longest tokens first, at most `max`
+pub fn search_terms(tokens: &BTreeSet<String>, max: usize) -> Vec<String> {
+    let mut terms: Vec<String> = tokens.iter().cloned().collect();
+    terms.sort_by(|a, b| b.chars().count().cmp(&a.chars().count()).then_with(|| a.cmp(b)));
+    terms.truncate(max);
+    terms
+}
+
+/// Levenshtein distance between two strings, counted in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, cb) in b_chars.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+        prev = current;
+    }
+
+    prev[b_chars.len()]
+}
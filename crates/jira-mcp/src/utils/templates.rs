@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+/// Names of the `{{placeholders}}` in a template text, in order of first appearance
+pub fn template_placeholders(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+
+        let name = rest[start + 2..start + 2 + len].trim().to_string();
+        if !name.is_empty() && !names.contains(&name) {
+            names.push(name);
+        }
+
+        rest = &rest[start + 4 + len..];
+    }
+
+    names
+}
+
+/// Replace `{{placeholders}}` with their values; returns the text and the placeholders left unfilled
+pub fn fill_template(text: &str, values: &BTreeMap<String, String>) -> (String, Vec<String>) {
+    let mut out = String::with_capacity(text.len());
+    let mut missing = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+
+        out.push_str(&rest[..start]);
+        let name = rest[start + 2..start + 2 + len].trim();
+
+        match values.get(name) {
+            Some(value) => out.push_str(value),
+            None => {
+                out.push_str(&rest[start..start + 4 + len]);
+                if !missing.iter().any(|m| m == name) {
+                    missing.push(name.to_string());
+                }
+            }
+        }
+
+        rest = &rest[start + 4 + len..];
+    }
+
+    out.push_str(rest);
+    (out, missing)
+}
+
+/// Required Markdown sections that are absent or have no content under their heading
+pub fn empty_sections(markdown: &str, required: &[String]) -> Vec<String> {
+    let heading_text = |line: &str| {
+        let trimmed = line.trim();
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        (level > 0).then(|| trimmed[level..].trim().to_string())
+    };
+
+    required
+        .iter()
+        .filter(|section| {
+            let mut lines = markdown.lines();
+            let found = lines
+                .by_ref()
+                .any(|l| heading_text(l).map(|h| h.eq_ignore_ascii_case(section)).unwrap_or(false));
+
+            if !found {
+                return true;
+            }
+
+            !lines
+                .take_while(|l| heading_text(l).is_none())
+                .any(|l| !l.trim().is_empty())
+        })
+        .cloned()
+        .collect()
+}
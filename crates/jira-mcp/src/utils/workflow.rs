@@ -0,0 +1,522 @@
+use std::collections::{HashMap, VecDeque};
+
+use serde_json::{json, Map, Value};
+
+use super::{edit_distance, normalize_status_category};
+
+/// A transition available on an issue right now, from `GET /issue/{key}/transitions`
+#[derive(Debug, Clone)]
+pub struct AvailableTransition {
+    pub id: String,
+    pub name: String,
+    pub to: String,
+    pub category: String,
+    /// Screen fields by id (present when fetched with `expand=transitions.fields`)
+    pub fields: Map<String, Value>,
+}
+
+pub fn parse_transitions(result: &Value) -> Vec<AvailableTransition> {
+    let text = |v: Option<&Value>| v.and_then(|s| s.as_str()).unwrap_or_default().to_string();
+
+    result
+        .get("transitions")
+        .and_then(|t| t.as_array())
+        .map(|arr| {
+            arr.iter()
+                .map(|t| AvailableTransition {
+                    id: text(t.get("id")),
+                    name: text(t.get("name")),
+                    to: text(t.get("to").and_then(|to| to.get("name"))),
+                    category: text(
+                        t.get("to")
+                            .and_then(|to| to.get("statusCategory"))
+                            .and_then(|sc| sc.get("name")),
+                    ),
+                    fields: t.get("fields").and_then(|f| f.as_object()).cloned().unwrap_or_default(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn loose_name(value: &str) -> String {
+    value.chars().filter(|c| c.is_alphanumeric()).flat_map(|c| c.to_lowercase()).collect()
+}
+
+/// How well a status matches the requested target, lower is better; `None` when it does not match.
+///
+/// Exact name, then name ignoring case/punctuation, then status category, then
+/// substring and small typos.
+pub fn status_match_rank(target: &str, status: &str, category: &str) -> Option<u8> {
+    let wanted = loose_name(target);
+    let candidate = loose_name(status);
+
+    if wanted.is_empty() || candidate.is_empty() {
+        return None;
+    }
+
+    if target.trim() == status {
+        Some(0)
+    } else if wanted == candidate {
+        Some(1)
+    } else if normalize_status_category(target).is_some_and(|c| c.eq_ignore_ascii_case(category)) {
+        Some(2)
+    } else if candidate.contains(&wanted) || wanted.contains(&candidate) {
+        Some(3)
+    } else if wanted.chars().count() > 3 && edit_distance(&wanted, &candidate) <= 2 {
+        Some(4)
+    } else {
+        None
+    }
+}
+
+/// Worst match rank acted on without confirmation: exact name, loose name or status category.
+/// Substring and typo matches are only offered as candidates.
+pub const CONFIDENT_MATCH_RANK: u8 = 2;
+
+/// Outcome of matching a requested status or transition name against the available transitions
+#[derive(Debug, Clone)]
+pub enum TransitionChoice<'a> {
+    /// A clear match that can be executed
+    Confident(&'a AvailableTransition),
+    /// Weak or conflicting matches, best first, for the caller to pick from
+    Candidates(Vec<&'a AvailableTransition>),
+    NoMatch,
+}
+
+/// Rank of a transition for the target, matching on the destination status or the transition name
+fn transition_rank(transition: &AvailableTransition, target: &str) -> Option<u8> {
+    let by_status = status_match_rank(target, &transition.to, &transition.category);
+    let by_name = loose_name(&transition.name).eq(&loose_name(target)).then_some(1);
+
+    match (by_status, by_name) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Pick the direct transition to the target. Category matches only count as confident when
+/// every such transition leads to the same status.
+pub fn resolve_transition<'a>(transitions: &'a [AvailableTransition], target: &str) -> TransitionChoice<'a> {
+    let mut ranked: Vec<(&AvailableTransition, u8)> = transitions
+        .iter()
+        .filter_map(|t| transition_rank(t, target).map(|r| (t, r)))
+        .collect();
+    ranked.sort_by_key(|(_, r)| *r);
+
+    let Some(&(best, best_rank)) = ranked.first() else {
+        return TransitionChoice::NoMatch;
+    };
+
+    let conflicting = best_rank == CONFIDENT_MATCH_RANK
+        && ranked
+            .iter()
+            .any(|(t, r)| *r == best_rank && !t.to.eq_ignore_ascii_case(&best.to));
+
+    if best_rank <= CONFIDENT_MATCH_RANK && !conflicting {
+        TransitionChoice::Confident(best)
+    } else {
+        TransitionChoice::Candidates(ranked.into_iter().map(|(t, _)| t).collect())
+    }
+}
+
+/// Position of a status category on the To Do → In Progress → Done axis
+pub fn category_index(category: &str) -> Option<usize> {
+    match normalize_status_category(category)? {
+        "To Do" => Some(0),
+        "In Progress" => Some(1),
+        _ => Some(2),
+    }
+}
+
+/// Screen fields that must be filled and were not supplied
+pub fn missing_required_fields(transition: &AvailableTransition, provided: &Map<String, Value>) -> Vec<Value> {
+    transition
+        .fields
+        .iter()
+        .filter(|(id, meta)| {
+            let required = meta.get("required").and_then(|r| r.as_bool()).unwrap_or(false);
+            let has_default = meta.get("hasDefaultValue").and_then(|d| d.as_bool()).unwrap_or(false);
+            required && !has_default && !provided.contains_key(*id)
+        })
+        .map(|(id, meta)| {
+            json!({
+                "id": id,
+                "name": meta.get("name"),
+                "allowed_values": compact_allowed_values(meta)
+            })
+        })
+        .collect()
+}
+
+/// Allowed values reduced to what is needed to pick one: id plus name or value
+pub fn compact_allowed_values(meta: &Value) -> Option<Vec<Value>> {
+    let values = meta.get("allowedValues")?.as_array()?;
+
+    Some(
+        values
+            .iter()
+            .map(|v| {
+                let mut out = Map::new();
+                for key in ["id", "name", "value", "key"] {
+                    if let Some(item) = v.get(key) {
+                        out.insert(key.to_string(), item.clone());
+                    }
+                }
+                if out.is_empty() { v.clone() } else { Value::Object(out) }
+            })
+            .collect(),
+    )
+}
+
+/// A transition screen field with its schema and allowed values, as shown by get_transitions
+pub fn describe_screen_field(id: &str, meta: &Value) -> Value {
+    json!({
+        "id": id,
+        "name": meta.get("name"),
+        "required": meta.get("required").and_then(|r| r.as_bool()).unwrap_or(false),
+        "has_default": meta.get("hasDefaultValue").and_then(|d| d.as_bool()).unwrap_or(false),
+        "schema": meta.get("schema"),
+        "operations": meta.get("operations"),
+        "allowed_values": compact_allowed_values(meta)
+    })
+}
+
+/// Whether a supplied value (string, `{id|name|value}` object or an array of them) is among the allowed values
+fn value_allowed(value: &Value, allowed: &[Value]) -> bool {
+    match value {
+        Value::Array(items) => items.iter().all(|item| value_allowed(item, allowed)),
+        Value::Null => true,
+        _ => {
+            let wanted: Vec<&str> = match value {
+                Value::String(s) => vec![s.as_str()],
+                Value::Object(obj) => ["id", "name", "value", "key"]
+                    .iter()
+                    .filter_map(|k| obj.get(*k).and_then(|v| v.as_str()))
+                    .collect(),
+                _ => return true,
+            };
+
+            allowed.iter().any(|a| {
+                ["id", "name", "value", "key"].iter().any(|k| {
+                    a.get(*k)
+                        .and_then(|v| v.as_str())
+                        .is_some_and(|v| wanted.iter().any(|w| w.eq_ignore_ascii_case(v)))
+                })
+            })
+        }
+    }
+}
+
+/// Problems with the supplied fields for a transition screen: missing required fields,
+/// fields not on the screen and values outside the allowed values
+pub fn screen_field_problems(transition: &AvailableTransition, provided: &Map<String, Value>) -> Vec<Value> {
+    let mut problems: Vec<Value> = missing_required_fields(transition, provided)
+        .into_iter()
+        .map(|mut field| {
+            field["problem"] = json!("required");
+            field
+        })
+        .collect();
+
+    for (id, value) in provided {
+        let Some(meta) = transition.fields.get(id) else {
+            problems.push(json!({
+                "id": id,
+                "problem": "not_on_screen",
+                "screen_fields": transition.fields.keys().collect::<Vec<_>>()
+            }));
+            continue;
+        };
+
+        let allowed = meta.get("allowedValues").and_then(|a| a.as_array());
+        if let Some(allowed) = allowed.filter(|a| !a.is_empty()) {
+            if !value_allowed(value, allowed) {
+                problems.push(json!({
+                    "id": id,
+                    "name": meta.get("name"),
+                    "problem": "not_allowed",
+                    "allowed_values": compact_allowed_values(meta)
+                }));
+            }
+        }
+    }
+
+    problems
+}
+
+#[derive(Debug, Clone)]
+struct WorkflowEdge {
+    name: String,
+    /// `None` for global transitions, available from every status
+    from: Option<Vec<String>>,
+    to: String,
+}
+
+/// Statuses and transitions of one workflow, from `POST /rest/api/3/workflows`
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowGraph {
+    /// Status reference → (name, category name)
+    statuses: HashMap<String, (String, String)>,
+    edges: Vec<WorkflowEdge>,
+}
+
+/// One planned hop through the workflow
+#[derive(Debug, Clone)]
+pub struct PlannedStep {
+    pub transition: String,
+    pub from: String,
+    pub to: String,
+}
+
+impl WorkflowGraph {
+    pub fn from_response(response: &Value) -> Option<Self> {
+        let workflow = response.get("workflows")?.as_array()?.first()?;
+
+        let statuses = response
+            .get("statuses")
+            .and_then(|s| s.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|s| {
+                        let reference = s.get("statusReference").or_else(|| s.get("id"))?.as_str()?;
+                        let name = s.get("name")?.as_str()?;
+                        let category = match s.get("statusCategory").and_then(|c| c.as_str()) {
+                            Some("TODO") => "To Do",
+                            Some("IN_PROGRESS") => "In Progress",
+                            Some("DONE") => "Done",
+                            _ => "",
+                        };
+                        Some((reference.to_string(), (name.to_string(), category.to_string())))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let edges = workflow
+            .get("transitions")
+            .and_then(|t| t.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter(|t| t.get("type").and_then(|ty| ty.as_str()) != Some("INITIAL"))
+                    .filter_map(|t| {
+                        let from: Vec<String> = t
+                            .get("links")
+                            .and_then(|l| l.as_array())
+                            .map(|links| {
+                                links
+                                    .iter()
+                                    .filter_map(|l| l.get("fromStatusReference").and_then(|f| f.as_str()))
+                                    .map(|f| f.to_string())
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+
+                        Some(WorkflowEdge {
+                            name: t.get("name")?.as_str()?.to_string(),
+                            from: (!from.is_empty()).then_some(from),
+                            to: t.get("toStatusReference")?.as_str()?.to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(WorkflowGraph { statuses, edges })
+    }
+
+    fn status_name(&self, reference: &str) -> String {
+        self.statuses
+            .get(reference)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| reference.to_string())
+    }
+
+    /// Shortest sequence of transitions from the current status to the best-matching target status,
+    /// with the `status_match_rank` of that target; empty when the current status is that target
+    pub fn shortest_path(&self, from_status_id: &str, target: &str) -> Option<(Vec<PlannedStep>, u8)> {
+        let ranked: Vec<(&String, u8)> = self
+            .statuses
+            .iter()
+            .filter_map(|(reference, (name, category))| {
+                status_match_rank(target, name, category).map(|r| (reference, r))
+            })
+            .collect();
+        let best = ranked.iter().map(|(_, r)| *r).min()?;
+        let targets: Vec<&String> = ranked.iter().filter(|(_, r)| *r == best).map(|(s, _)| *s).collect();
+
+        if targets.iter().any(|t| *t == from_status_id) {
+            return Some((Vec::new(), best));
+        }
+
+        let mut previous: HashMap<String, (String, usize)> = HashMap::new();
+        let mut queue = VecDeque::from([from_status_id.to_string()]);
+        previous.insert(from_status_id.to_string(), (String::new(), usize::MAX));
+
+        while let Some(current) = queue.pop_front() {
+            if targets.contains(&&current) {
+                let mut steps = Vec::new();
+                let mut at = current;
+                while let Some((prev, edge)) = previous.get(&at).filter(|(_, e)| *e != usize::MAX) {
+                    steps.push(PlannedStep {
+                        transition: self.edges[*edge].name.clone(),
+                        from: self.status_name(prev),
+                        to: self.status_name(&at),
+                    });
+                    at = prev.clone();
+                }
+                steps.reverse();
+                return Some((steps, best));
+            }
+
+            for (index, edge) in self.edges.iter().enumerate() {
+                let reachable = edge.from.as_ref().is_none_or(|f| f.contains(&current));
+                if reachable && edge.to != current && !previous.contains_key(&edge.to) {
+                    previous.insert(edge.to.clone(), (current.clone(), index));
+                    queue.push_back(edge.to.clone());
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> WorkflowGraph {
+        let status = |id: &str, name: &str, category: &str| {
+            json!({ "statusReference": id, "name": name, "statusCategory": category })
+        };
+        let edge = |name: &str, from: &[&str], to: &str| {
+            json!({
+                "name": name,
+                "type": if from.is_empty() { "GLOBAL" } else { "DIRECTED" },
+                "links": from.iter().map(|f| json!({ "fromStatusReference": f })).collect::<Vec<_>>(),
+                "toStatusReference": to
+            })
+        };
+
+        let response = json!({
+            "statuses": [
+                status("1", "To Do", "TODO"),
+                status("2", "In Progress", "IN_PROGRESS"),
+                status("3", "In Review", "IN_PROGRESS"),
+                status("4", "Done", "DONE"),
+                status("5", "Archived", "DONE"),
+                status("6", "Orphaned", "TODO")
+            ],
+            "workflows": [{
+                "transitions": [
+                    { "name": "Create", "type": "INITIAL", "links": [], "toStatusReference": "1" },
+                    edge("Start", &["1"], "2"),
+                    edge("Review", &["2"], "3"),
+                    edge("Approve", &["3"], "4"),
+                    edge("Reopen", &["4"], "1"),
+                    edge("Archive", &[], "5")
+                ]
+            }]
+        });
+
+        WorkflowGraph::from_response(&response).unwrap()
+    }
+
+    fn names(steps: &[PlannedStep]) -> Vec<&str> {
+        steps.iter().map(|s| s.transition.as_str()).collect()
+    }
+
+    fn transition(id: &str, name: &str, to: &str, category: &str) -> AvailableTransition {
+        AvailableTransition {
+            id: id.into(),
+            name: name.into(),
+            to: to.into(),
+            category: category.into(),
+            fields: Map::new(),
+        }
+    }
+
+    #[test]
+    fn shortest_path_walks_directed_transitions() {
+        let (steps, rank) = graph().shortest_path("1", "Done").unwrap();
+        assert_eq!(names(&steps), ["Start", "Review", "Approve"]);
+        assert_eq!(steps[0].from, "To Do");
+        assert_eq!(steps[2].to, "Done");
+        assert_eq!(rank, 0);
+    }
+
+    #[test]
+    fn shortest_path_uses_global_transitions_from_any_status() {
+        let (steps, _) = graph().shortest_path("3", "archived").unwrap();
+        assert_eq!(names(&steps), ["Archive"]);
+        assert_eq!(steps[0].from, "In Review");
+    }
+
+    #[test]
+    fn shortest_path_returns_none_for_unreachable_target() {
+        assert!(graph().shortest_path("1", "Orphaned").is_none());
+        assert!(graph().shortest_path("1", "No Such Status").is_none());
+    }
+
+    #[test]
+    fn shortest_path_is_empty_when_already_at_target() {
+        let (steps, rank) = graph().shortest_path("4", "Done").unwrap();
+        assert!(steps.is_empty());
+        assert_eq!(rank, 0);
+    }
+
+    #[test]
+    fn status_match_rank_orders_match_kinds() {
+        assert_eq!(status_match_rank("In Progress", "In Progress", "In Progress"), Some(0));
+        assert_eq!(status_match_rank("in-progress", "In Progress", "In Progress"), Some(1));
+        assert_eq!(status_match_rank("done", "Closed", "Done"), Some(2));
+        assert_eq!(status_match_rank("Review", "In Review", "In Progress"), Some(3));
+        assert_eq!(status_match_rank("Reveiw", "Review", "In Progress"), Some(4));
+        assert_eq!(status_match_rank("Blocked", "Done", "Done"), None);
+        assert_eq!(status_match_rank("", "Done", "Done"), None);
+    }
+
+    #[test]
+    fn status_match_rank_skips_typos_in_short_names() {
+        assert_eq!(status_match_rank("Dne", "Done", "Done"), None);
+    }
+
+    #[test]
+    fn resolve_transition_only_trusts_close_matches() {
+        let transitions = [
+            transition("11", "Start Progress", "In Progress", "In Progress"),
+            transition("21", "Send to Review", "In Review", "In Progress"),
+            transition("31", "Close", "Done", "Done"),
+        ];
+
+        assert!(matches!(resolve_transition(&transitions, "done"), TransitionChoice::Confident(t) if t.id == "31"));
+        assert!(matches!(resolve_transition(&transitions, "start progress"), TransitionChoice::Confident(t) if t.id == "11"));
+        assert!(matches!(resolve_transition(&transitions, "Review"), TransitionChoice::Candidates(c) if c[0].id == "21"));
+        assert!(matches!(resolve_transition(&transitions, "Blocked"), TransitionChoice::NoMatch));
+    }
+
+    #[test]
+    fn resolve_transition_lists_conflicting_category_matches() {
+        let transitions = [
+            transition("31", "Close", "Done", "Done"),
+            transition("41", "Won't Do", "Rejected", "Done"),
+        ];
+
+        assert!(matches!(resolve_transition(&transitions, "complete"), TransitionChoice::Candidates(c) if c.len() == 2));
+        assert!(matches!(resolve_transition(&transitions, "Done"), TransitionChoice::Confident(t) if t.id == "31"));
+    }
+
+    #[test]
+    fn value_allowed_matches_ids_names_and_arrays() {
+        let allowed = [json!({ "id": "1", "name": "Fixed" }), json!({ "id": "2", "value": "Won't Fix" })];
+
+        assert!(value_allowed(&json!("fixed"), &allowed));
+        assert!(value_allowed(&json!({ "id": "2" }), &allowed));
+        assert!(value_allowed(&json!([{ "name": "Fixed" }, { "value": "won't fix" }]), &allowed));
+        assert!(value_allowed(&Value::Null, &allowed));
+        assert!(value_allowed(&json!(3), &allowed));
+        assert!(!value_allowed(&json!("Duplicate"), &allowed));
+        assert!(!value_allowed(&json!(["Fixed", "Duplicate"]), &allowed));
+    }
+}